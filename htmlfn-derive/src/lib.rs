@@ -0,0 +1,782 @@
+//! Derive macros for htmlfn.
+//!
+//! This crate is re-exported by htmlfn if its `derive` feature is enabled.
+//! It should not be used directly.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Expr, Fields, Ident, LitStr, parse_macro_input,
+};
+
+
+//------------ derive(Content) -----------------------------------------------
+
+/// Derives `Content` and the content category of its element for a struct.
+///
+/// The struct is rendered as an element containing its fields in order.
+/// The element is a `div` unless given via `#[html(tag = "…")]` on the
+/// struct. Its classes can be given via `#[html(class = "…")]`. The tag
+/// must be the name of an element of the `html` module that can have
+/// content. The struct implements the category marker trait for the
+/// element, e.g., `Flow` for a `div` or `ListItems` for an `li`, or none
+/// if the element only appears in specific parents.
+///
+/// By default, a field is rendered as is, so its type must implement
+/// `Content`. The field can be modified via the following attributes:
+///
+/// * `#[html(tag = "…")]` wraps the field in the given element,
+/// * `#[html(class = "…")]` sets the classes of that element,
+/// * `#[html(label = "…")]` renders a `dt` element with the label before
+///   the field which is wrapped in a `dd` element; the struct’s element
+///   then has to be a `dl` and is one by default,
+/// * `#[html(display)]` renders the field via its `Display` impl, and
+/// * `#[html(skip)]` doesn’t render the field at all.
+#[proc_macro_derive(Content, attributes(html))]
+pub fn derive_content(
+    input: proc_macro::TokenStream
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match content(input) {
+        Ok(res) => res.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn content(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match input.data {
+        Data::Struct(data) => data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input.ident, "Content can only be derived for structs"
+            ))
+        }
+    };
+    let options = Options::from_attrs(&input.attrs)?;
+    if options.skip || options.display || options.label.is_some() {
+        return Err(syn::Error::new_spanned(
+            input.ident,
+            "only 'tag' and 'class' are allowed for the struct"
+        ))
+    }
+
+    let mut content = quote!(());
+    let mut bounds = Vec::new();
+    let mut label = None;
+    for (idx, field) in fields.iter().enumerate().rev() {
+        let mut field_options = Options::from_attrs(&field.attrs)?;
+        if field_options.skip {
+            continue
+        }
+        let member = match field.ident.as_ref() {
+            Some(ident) => quote!(#ident),
+            None => {
+                let idx = syn::Index::from(idx);
+                quote!(#idx)
+            }
+        };
+        let ty = &field.ty;
+        let mut value = if field_options.display {
+            bounds.push(quote!(#ty: ::std::fmt::Display));
+            quote!(::htmlfn::utils::display(self.#member))
+        }
+        else {
+            bounds.push(quote!(#ty: ::htmlfn::core::Content));
+            quote!(self.#member)
+        };
+        if let Some(field_label) = field_options.label.as_ref() {
+            match field_options.tag.as_ref() {
+                Some(tag) if tag != "dd" => {
+                    return Err(syn::Error::new(
+                        tag.span(), "a field with 'label' must be a 'dd'"
+                    ))
+                }
+                Some(_) => { }
+                None => {
+                    field_options.tag = Some(
+                        Ident::new("dd", field_label.span())
+                    );
+                }
+            }
+            label = Some(field_label.span());
+        }
+        if let Some(tag) = field_options.tag.as_ref() {
+            value = field_options.element(tag, value);
+        }
+        else if field_options.class.is_some() {
+            return Err(syn::Error::new_spanned(
+                field, "'class' requires 'tag'"
+            ))
+        }
+        if let Some(label) = field_options.label {
+            value = quote!((::htmlfn::html::dt(#label), #value));
+        }
+        content = quote!((#value, #content));
+    }
+
+    let tag = match (options.tag.clone(), label) {
+        (Some(tag), Some(_)) if tag != "dl" => {
+            return Err(syn::Error::new(
+                tag.span(), "a struct with labelled fields must be a 'dl'"
+            ))
+        }
+        (Some(tag), _) => tag,
+        (None, Some(label)) => Ident::new("dl", label),
+        (None, None) => Ident::new("div", proc_macro2::Span::call_site()),
+    };
+    let element = options.element(&tag, content);
+    let name = input.ident;
+    let mut generics = input.generics;
+    if !bounds.is_empty() {
+        let where_clause = generics.make_where_clause();
+        // The fields were processed in reverse order.
+        for bound in bounds.into_iter().rev() {
+            where_clause.predicates.push(syn::parse2(bound)?);
+        }
+    }
+    let (impl_generics, ty_generics, where_clause)
+        = generics.split_for_impl();
+    let categories = match category(&tag) {
+        Some(Category::Phrasing) => vec![
+            quote!(Flow), quote!(Phrasing)
+        ],
+        Some(Category::Flow) => vec![quote!(Flow)],
+        Some(Category::ListItems) => vec![quote!(ListItems)],
+        Some(Category::DescriptionItems) => {
+            vec![quote!(DescriptionItems)]
+        }
+        Some(Category::TableRows) => vec![quote!(TableRows)],
+        Some(Category::TableCells) => vec![quote!(TableCells)],
+        None => Vec::new(),
+    };
+    Ok(quote! {
+        impl #impl_generics ::htmlfn::core::Content
+        for #name #ty_generics #where_clause {
+            fn render_content(self, target: &mut ::htmlfn::core::Target) {
+                ::htmlfn::core::Content::render_content(#element, target)
+            }
+        }
+
+        #(
+            impl #impl_generics ::htmlfn::core::#categories
+            for #name #ty_generics #where_clause { }
+        )*
+    })
+}
+
+
+//------------ Elements ------------------------------------------------------
+
+/// The content category of an element rendered by the derive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Category {
+    Flow,
+    Phrasing,
+    ListItems,
+    DescriptionItems,
+    TableRows,
+    TableCells,
+}
+
+/// The elements with content the derive can render.
+///
+/// These are the elements of the `html` module with an `attrs` function
+/// taking arbitrary content and the category of their content.
+const ELEMENTS: &[(&str, Option<Category>)] = &[
+    ("a", Some(Category::Phrasing)),
+    ("abbr", Some(Category::Phrasing)),
+    ("address", Some(Category::Flow)),
+    ("article", Some(Category::Flow)),
+    ("aside", Some(Category::Flow)),
+    ("b", Some(Category::Phrasing)),
+    ("blockquote", Some(Category::Flow)),
+    ("cite", Some(Category::Phrasing)),
+    ("code", Some(Category::Phrasing)),
+    ("dd", Some(Category::DescriptionItems)),
+    ("del", Some(Category::Phrasing)),
+    ("details", Some(Category::Flow)),
+    ("dfn", Some(Category::Phrasing)),
+    ("dialog", Some(Category::Flow)),
+    ("div", Some(Category::Flow)),
+    ("dl", Some(Category::Flow)),
+    ("dt", Some(Category::DescriptionItems)),
+    ("em", Some(Category::Phrasing)),
+    ("fieldset", Some(Category::Flow)),
+    ("figcaption", None),
+    ("figure", Some(Category::Flow)),
+    ("footer", Some(Category::Flow)),
+    ("h1", Some(Category::Flow)),
+    ("h2", Some(Category::Flow)),
+    ("h3", Some(Category::Flow)),
+    ("h4", Some(Category::Flow)),
+    ("h5", Some(Category::Flow)),
+    ("h6", Some(Category::Flow)),
+    ("header", Some(Category::Flow)),
+    ("hgroup", Some(Category::Flow)),
+    ("i", Some(Category::Phrasing)),
+    ("ins", Some(Category::Phrasing)),
+    ("kbd", Some(Category::Phrasing)),
+    ("label", Some(Category::Phrasing)),
+    ("legend", None),
+    ("li", Some(Category::ListItems)),
+    ("main", Some(Category::Flow)),
+    ("mark", Some(Category::Phrasing)),
+    ("menu", Some(Category::Flow)),
+    ("nav", Some(Category::Flow)),
+    ("ol", Some(Category::Flow)),
+    ("p", Some(Category::Flow)),
+    ("pre", Some(Category::Flow)),
+    ("q", Some(Category::Phrasing)),
+    ("s", Some(Category::Phrasing)),
+    ("samp", Some(Category::Phrasing)),
+    ("section", Some(Category::Flow)),
+    ("small", Some(Category::Phrasing)),
+    ("span", Some(Category::Phrasing)),
+    ("strong", Some(Category::Phrasing)),
+    ("sub", Some(Category::Phrasing)),
+    ("summary", None),
+    ("sup", Some(Category::Phrasing)),
+    ("table", Some(Category::Flow)),
+    ("td", Some(Category::TableCells)),
+    ("th", Some(Category::TableCells)),
+    ("time", Some(Category::Phrasing)),
+    ("tr", Some(Category::TableRows)),
+    ("u", Some(Category::Phrasing)),
+    ("ul", Some(Category::Flow)),
+    ("var", Some(Category::Phrasing)),
+];
+
+/// The void elements which can’t have content.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link",
+    "meta", "source", "track", "wbr",
+];
+
+/// Returns the category of an element in `ELEMENTS`.
+fn category(tag: &Ident) -> Option<Category> {
+    ELEMENTS.iter().find(|item| tag == item.0).and_then(|item| item.1)
+}
+
+/// Parses the tag of an element, checking that the derive can render it.
+fn parse_tag(tag: &LitStr) -> syn::Result<Ident> {
+    let value = tag.value();
+    if VOID_ELEMENTS.contains(&value.as_str()) {
+        return Err(syn::Error::new(
+            tag.span(),
+            format!("void element '{}' can't have content", value)
+        ))
+    }
+    if !ELEMENTS.iter().any(|item| item.0 == value) {
+        return Err(syn::Error::new(
+            tag.span(), format!("unsupported element '{}'", value)
+        ))
+    }
+    tag.parse()
+}
+
+
+//------------ derive(Form) --------------------------------------------------
+
+/// Derives `Form` for a struct with named fields.
+///
+/// See the `form` module of htmlfn for the supported attributes.
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(
+    input: proc_macro::TokenStream
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match form(input) {
+        Ok(res) => res.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn form(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match input.data {
+        Data::Struct(data) => data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input.ident, "Form can only be derived for structs"
+            ))
+        }
+    };
+    let fields = match fields {
+        Fields::Named(fields) => fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input.ident, "Form can only be derived for named fields"
+            ))
+        }
+    };
+
+    let mut specs = Vec::new();
+    let mut number_types = Vec::new();
+    let mut names = Vec::new();
+    let mut idents = Vec::new();
+    let mut types = Vec::new();
+    for field in &fields {
+        let options = FormOptions::from_attrs(&field.attrs)?;
+        let ident = field.ident.clone().ok_or_else(|| {
+            syn::Error::new_spanned(field, "missing field name")
+        })?;
+        let name = ident.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name).to_string();
+        let label = match options.label {
+            Some(label) => label.value(),
+            None => default_label(&name),
+        };
+        let ty = &field.ty;
+        let mut spec = quote!(
+            ::htmlfn::form::Field::new::<#ty>(#name, #label)
+        );
+        let controls = [
+            options.input.is_some(), options.textarea,
+            options.options.is_some(),
+        ];
+        if controls.iter().filter(|item| **item).count() > 1 {
+            return Err(syn::Error::new_spanned(
+                field,
+                "only one of 'input', 'textarea', and 'options' is allowed"
+            ))
+        }
+        if let Some(input) = options.input {
+            spec = quote!(
+                #spec.kind(::htmlfn::form::FieldKind::Input(#input))
+            );
+        }
+        if options.textarea {
+            spec = quote!(#spec.kind(::htmlfn::form::FieldKind::Textarea));
+        }
+        let select = options.options;
+        if let Some(select) = select.as_ref() {
+            spec = quote!(
+                #spec.kind(::htmlfn::form::FieldKind::Select(#select))
+            );
+        }
+        if options.min.is_some() || options.max.is_some() {
+            number_types.push(ty.clone());
+        }
+        if let Some(min) = options.min {
+            spec = quote!(#spec.min((#min) as f64));
+        }
+        if let Some(max) = options.max {
+            spec = quote!(#spec.max((#max) as f64));
+        }
+        if let Some(pattern) = options.pattern {
+            if options.textarea || select.is_some() {
+                return Err(syn::Error::new_spanned(
+                    pattern,
+                    "'pattern' can't be used with 'textarea' or 'options'"
+                ))
+            }
+            spec = quote!(#spec.pattern(#pattern));
+        }
+        specs.push(spec);
+        names.push(name);
+        idents.push(ident);
+        types.push(ty.clone());
+    }
+    let indexes = 0..idents.len();
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause)
+        = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::htmlfn::form::Form
+        for #name #ty_generics #where_clause {
+            fn fields() -> ::std::vec::Vec<::htmlfn::form::Field> {
+                // Bounds are only allowed for numbers.
+                fn number_field<T: ::htmlfn::form::NumberField>() { }
+                #( number_field::<#number_types>(); )*
+
+                ::std::vec![ #( #specs ),* ]
+            }
+
+            fn from_form_data(
+                data: &::htmlfn::form::FormData
+            ) -> ::std::result::Result<Self, ::htmlfn::form::FormErrors> {
+                let fields = <Self as ::htmlfn::form::Form>::fields();
+                let mut errors = ::htmlfn::form::FormErrors::new();
+                #(
+                    let #idents = fields[#indexes].parse::<#types>(
+                        data, &mut errors
+                    );
+                )*
+                match ( #( #idents, )* ) {
+                    ( #( ::std::option::Option::Some(#idents), )* )
+                    if errors.is_empty() => {
+                        ::std::result::Result::Ok(Self { #( #idents ),* })
+                    }
+                    _ => ::std::result::Result::Err(errors)
+                }
+            }
+
+            fn to_form_data(&self) -> ::htmlfn::form::FormData {
+                let mut data = ::htmlfn::form::FormData::new();
+                #(
+                    data.append_field(#names, &self.#idents);
+                )*
+                data
+            }
+        }
+    })
+}
+
+/// Returns the default label for a field name.
+///
+/// Underscores are replaced by spaces and the first letter is capitalized.
+fn default_label(name: &str) -> String {
+    let mut res = String::new();
+    for (idx, ch) in name.chars().enumerate() {
+        match ch {
+            '_' => res.push(' '),
+            ch if idx == 0 => res.extend(ch.to_uppercase()),
+            ch => res.push(ch),
+        }
+    }
+    res
+}
+
+
+//------------ Options -------------------------------------------------------
+
+/// The options given via `#[html(…)]` attributes.
+#[derive(Default)]
+struct Options {
+    tag: Option<Ident>,
+    class: Option<LitStr>,
+    label: Option<LitStr>,
+    display: bool,
+    skip: bool,
+}
+
+impl Options {
+    fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = Options::default();
+        for attr in attrs {
+            if !attr.path().is_ident("html") {
+                continue
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    res.tag = Some(parse_tag(&meta.value()?.parse()?)?);
+                }
+                else if meta.path.is_ident("class") {
+                    res.class = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("label") {
+                    res.label = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("display") {
+                    res.display = true;
+                }
+                else if meta.path.is_ident("skip") {
+                    res.skip = true;
+                }
+                else {
+                    return Err(meta.error("unknown html attribute"))
+                }
+                Ok(())
+            })?;
+        }
+        Ok(res)
+    }
+
+    /// Returns an expression for the element `tag` with the given content.
+    fn element(&self, tag: &Ident, content: TokenStream) -> TokenStream {
+        let attrs = match self.class.as_ref() {
+            Some(class) => quote!(::htmlfn::html::attr::class(#class)),
+            None => quote!(()),
+        };
+        quote!(::htmlfn::html::#tag::attrs(#attrs, #content))
+    }
+}
+
+
+//------------ FormOptions ---------------------------------------------------
+
+/// The options given via `#[form(…)]` attributes.
+#[derive(Default)]
+struct FormOptions {
+    label: Option<LitStr>,
+    input: Option<LitStr>,
+    textarea: bool,
+    options: Option<Expr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    pattern: Option<LitStr>,
+}
+
+impl FormOptions {
+    fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut res = FormOptions::default();
+        for attr in attrs {
+            if !attr.path().is_ident("form") {
+                continue
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    res.label = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("input") {
+                    res.input = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("textarea") {
+                    res.textarea = true;
+                }
+                else if meta.path.is_ident("options") {
+                    res.options = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("min") {
+                    res.min = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("max") {
+                    res.max = Some(meta.value()?.parse()?);
+                }
+                else if meta.path.is_ident("pattern") {
+                    res.pattern = Some(meta.value()?.parse()?);
+                }
+                else {
+                    return Err(meta.error("unknown form attribute"))
+                }
+                Ok(())
+            })?;
+        }
+        Ok(res)
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse_quote;
+
+    /// Returns the derived content impl with white space removed.
+    fn derive(input: DeriveInput) -> String {
+        content(input).unwrap().to_string().replace(' ', "")
+    }
+
+    fn derive_err(input: DeriveInput) -> String {
+        content(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn content_div() {
+        let res = derive(parse_quote! {
+            #[html(class = "card")]
+            struct Card<T> {
+                title: T,
+                #[html(tag = "p", display)]
+                count: u32,
+                #[html(skip)]
+                _hidden: (),
+            }
+        });
+        assert!(res.contains(
+            "::htmlfn::html::div::attrs(\
+             ::htmlfn::html::attr::class(\"card\"),(self.title,\
+             (::htmlfn::html::p::attrs((),\
+             ::htmlfn::utils::display(self.count)),())))"
+        ), "{}", res);
+        assert!(res.contains(
+            "whereT:::htmlfn::core::Content,u32:::std::fmt::Display"
+        ), "{}", res);
+        assert!(res.contains("impl<T>::htmlfn::core::FlowforCard<T>"));
+        assert!(!res.contains("_hidden"));
+        assert!(!res.contains("Phrasing"));
+    }
+
+    #[test]
+    fn content_categories() {
+        let categories = |tag: &str| {
+            let tag = LitStr::new(tag, proc_macro2::Span::call_site());
+            let res = derive(parse_quote! {
+                #[html(tag = #tag)]
+                struct Item(String);
+            });
+            [
+                "Flow", "Phrasing", "ListItems", "DescriptionItems",
+                "TableRows", "TableCells",
+            ].into_iter().filter(|category| {
+                res.contains(&format!("::htmlfn::core::{}for", category))
+            }).collect::<Vec<_>>()
+        };
+        assert_eq!(categories("section"), ["Flow"]);
+        assert_eq!(categories("span"), ["Flow", "Phrasing"]);
+        assert_eq!(categories("li"), ["ListItems"]);
+        assert_eq!(categories("dd"), ["DescriptionItems"]);
+        assert_eq!(categories("dt"), ["DescriptionItems"]);
+        assert_eq!(categories("tr"), ["TableRows"]);
+        assert_eq!(categories("td"), ["TableCells"]);
+        assert_eq!(categories("th"), ["TableCells"]);
+        assert!(categories("summary").is_empty());
+    }
+
+    #[test]
+    fn content_labels() {
+        let res = derive(parse_quote! {
+            struct Person {
+                #[html(label = "Name")]
+                name: String,
+            }
+        });
+        assert!(res.contains(
+            "::htmlfn::html::dl::attrs((),((::htmlfn::html::dt(\"Name\"),\
+             ::htmlfn::html::dd::attrs((),self.name)),()))"
+        ), "{}", res);
+        assert!(res.contains("::htmlfn::core::FlowforPerson"));
+
+        assert_eq!(
+            derive_err(parse_quote! {
+                #[html(tag = "div")]
+                struct Person {
+                    #[html(label = "Name")]
+                    name: String,
+                }
+            }),
+            "a struct with labelled fields must be a 'dl'"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                struct Person {
+                    #[html(label = "Name", tag = "p")]
+                    name: String,
+                }
+            }),
+            "a field with 'label' must be a 'dd'"
+        );
+    }
+
+    #[test]
+    fn content_errors() {
+        assert_eq!(
+            derive_err(parse_quote! {
+                #[html(tag = "img")]
+                struct Image(String);
+            }),
+            "void element 'img' can't have content"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                struct Field {
+                    #[html(tag = "input")]
+                    value: String,
+                }
+            }),
+            "void element 'input' can't have content"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                #[html(tag = "blink")]
+                struct Old(String);
+            }),
+            "unsupported element 'blink'"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                struct Item {
+                    #[html(class = "a")]
+                    value: String,
+                }
+            }),
+            "'class' requires 'tag'"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                #[html(display)]
+                struct Item(String);
+            }),
+            "only 'tag' and 'class' are allowed for the struct"
+        );
+        assert_eq!(
+            derive_err(parse_quote! {
+                enum Item { A }
+            }),
+            "Content can only be derived for structs"
+        );
+    }
+
+    #[test]
+    fn form_fields() {
+        let res = form(parse_quote! {
+            struct Order {
+                #[form(options = COLORS, label = "Colour")]
+                color: String,
+                #[form(min = 1, max = 10)]
+                count: Option<u8>,
+                #[form(textarea)]
+                note_text: String,
+                #[form(pattern = "[a-z]*")]
+                code: String,
+            }
+        }).unwrap().to_string().replace(' ', "");
+        assert!(res.contains(
+            "::htmlfn::form::Field::new::<String>(\"color\",\"Colour\")\
+             .kind(::htmlfn::form::FieldKind::Select(COLORS))"
+        ), "{}", res);
+        assert!(res.contains(
+            "::htmlfn::form::Field::new::<Option<u8>>(\"count\",\"Count\")\
+             .min((1)asf64).max((10)asf64)"
+        ), "{}", res);
+        assert!(res.contains(
+            "::htmlfn::form::Field::new::<String>(\"note_text\",\
+             \"Notetext\").kind(::htmlfn::form::FieldKind::Textarea),"
+        ), "{}", res);
+        assert!(res.contains(
+            "::htmlfn::form::Field::new::<String>(\"code\",\"Code\")\
+             .pattern(\"[a-z]*\")"
+        ), "{}", res);
+        assert!(res.contains("number_field::<Option<u8>>();"), "{}", res);
+        assert_eq!(res.matches("number_field::<").count(), 1);
+    }
+
+    #[test]
+    fn form_errors() {
+        let err = |input| form(input).unwrap_err().to_string();
+        assert_eq!(
+            err(parse_quote! {
+                struct Order {
+                    #[form(textarea, options = COLORS)]
+                    color: String,
+                }
+            }),
+            "only one of 'input', 'textarea', and 'options' is allowed"
+        );
+        assert_eq!(
+            err(parse_quote! {
+                struct Order {
+                    #[form(textarea, pattern = "[a-z]*")]
+                    note: String,
+                }
+            }),
+            "'pattern' can't be used with 'textarea' or 'options'"
+        );
+        assert_eq!(
+            err(parse_quote! {
+                struct Order {
+                    #[form(options = COLORS, pattern = "[a-z]*")]
+                    color: String,
+                }
+            }),
+            "'pattern' can't be used with 'textarea' or 'options'"
+        );
+        assert_eq!(
+            err(parse_quote! {
+                struct Order {
+                    #[form(step = 1)]
+                    count: u8,
+                }
+            }),
+            "unknown form attribute"
+        );
+        assert_eq!(
+            err(parse_quote! {
+                struct Order(u8);
+            }),
+            "Form can only be derived for named fields"
+        );
+    }
+}
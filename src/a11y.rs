@@ -0,0 +1,557 @@
+//! Checking rendered HTML for accessibility problems.
+//!
+//! The linter in this module walks a rendered [`Target`] and reports
+//! problems that make content hard or impossible to use with assistive
+//! technology. The problems roughly follow the failures described by
+//! WCAG. Like the [`check`][crate::check] module, it is meant to be used
+//! in tests, e.g., via `assert_eq!(a11y::check(content), [])`.
+//!
+//! The linter builds on the diagnostics of the conformance checker: those
+//! that are accessibility problems, too, are included in the issues.
+
+use std::fmt;
+use std::collections::HashSet;
+use crate::check::{self, Diagnostic, DiagnosticKind};
+use crate::core::{Content, Target};
+use crate::parse::{Attribute, Token, Tokenizer};
+
+
+//------------ check ---------------------------------------------------------
+
+/// Renders the content and checks the result.
+pub fn check(content: impl Content) -> Vec<Issue> {
+    check_target(&content.render())
+}
+
+/// Checks already rendered content.
+///
+/// Content that is not valid UTF-8 is only checked up to the first
+/// invalid byte.
+pub fn check_target(target: &Target) -> Vec<Issue> {
+    let data = match std::str::from_utf8(target.as_ref()) {
+        Ok(data) => data,
+        Err(err) => {
+            std::str::from_utf8(
+                &target.as_ref()[..err.valid_up_to()]
+            ).unwrap_or_default()
+        }
+    };
+    let mut linter = Linter::default();
+    for (offset, token) in Tokenizer::new(data) {
+        linter.token(offset, token);
+    }
+    let mut issues = linter.finish();
+    issues.extend(
+        check::check_target(target).into_iter().filter_map(
+            Issue::from_diagnostic
+        )
+    );
+    issues.sort_by_key(|item| item.offset);
+    issues
+}
+
+
+//------------ Issue ---------------------------------------------------------
+
+/// An accessibility problem found in rendered HTML.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Issue {
+    /// The byte offset in the rendered data where the problem was found.
+    pub offset: usize,
+
+    /// What kind of problem was found.
+    pub kind: IssueKind,
+}
+
+impl Issue {
+    /// Converts a conformance diagnostic if it is an accessibility issue.
+    fn from_diagnostic(diagnostic: Diagnostic) -> Option<Self> {
+        let kind = match diagnostic.kind {
+            DiagnosticKind::MissingAlt => IssueKind::MissingAlt,
+            DiagnosticKind::DuplicateId(id) => IssueKind::DuplicateId(id),
+            DiagnosticKind::LabelWithoutControl => {
+                IssueKind::LabelWithoutControl
+            }
+            _ => return None,
+        };
+        Some(Issue { offset: diagnostic.offset, kind })
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.offset, self.kind)
+    }
+}
+
+
+//------------ IssueKind -----------------------------------------------------
+
+/// The kind of an accessibility problem.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssueKind {
+    /// An `img` element without an `alt` attribute.
+    ///
+    /// Decorative images should have an empty `alt` attribute.
+    MissingAlt,
+
+    /// A form control without a label.
+    ///
+    /// A control is labelled by a `label` element containing it or
+    /// referring to it, or by an `aria-label`, `aria-labelledby`, or
+    /// `title` attribute.
+    UnlabeledControl(String),
+
+    /// A heading that is more than one level below the previous one.
+    SkippedHeadingLevel {
+        from: u8,
+        to: u8,
+    },
+
+    /// A link without an accessible name.
+    EmptyLink,
+
+    /// A button without an accessible name.
+    EmptyButton,
+
+    /// An ARIA attribute referring to an id that doesn't exist.
+    MissingReference {
+        attribute: String,
+        id: String,
+    },
+
+    /// An `html` element without a `lang` attribute.
+    MissingLang,
+
+    /// The same id is used by more than one element.
+    ///
+    /// References to the id, e.g., by labels or ARIA attributes, are
+    /// ambiguous.
+    DuplicateId(String),
+
+    /// A `label` that doesn't refer to or contain a form control.
+    LabelWithoutControl,
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IssueKind::MissingAlt => {
+                f.write_str("'img' element without 'alt' attribute")
+            }
+            IssueKind::UnlabeledControl(name) => {
+                write!(f, "form control '{}' without a label", name)
+            }
+            IssueKind::SkippedHeadingLevel { from, to } => {
+                write!(f, "heading level skipped from h{} to h{}", from, to)
+            }
+            IssueKind::EmptyLink => f.write_str("link without a name"),
+            IssueKind::EmptyButton => f.write_str("button without a name"),
+            IssueKind::MissingReference { attribute, id } => {
+                write!(
+                    f, "'{}' refers to missing id '{}'", attribute, id
+                )
+            }
+            IssueKind::MissingLang => {
+                f.write_str("'html' element without 'lang' attribute")
+            }
+            IssueKind::DuplicateId(id) => write!(f, "duplicate id '{}'", id),
+            IssueKind::LabelWithoutControl => {
+                f.write_str("'label' without matching form control")
+            }
+        }
+    }
+}
+
+
+//------------ Linter --------------------------------------------------------
+
+#[derive(Default)]
+struct Linter<'a> {
+    /// The currently open elements.
+    open: Vec<Open<'a>>,
+
+    /// The ids seen so far.
+    ids: HashSet<String>,
+
+    /// The targets of all label elements.
+    label_targets: HashSet<String>,
+
+    /// Controls with an id but no other label and their offset.
+    unlabeled: Vec<(String, &'a str, usize)>,
+
+    /// Id references of ARIA attributes with their attribute and offset.
+    references: Vec<(String, &'a str, usize)>,
+
+    /// The level of the previous heading.
+    heading: Option<u8>,
+
+    issues: Vec<Issue>,
+}
+
+struct Open<'a> {
+    name: &'a str,
+
+    /// Are we in foreign content, i.e., SVG or MathML?
+    foreign: bool,
+
+    /// Is the element hidden from assistive technology?
+    hidden: bool,
+
+    /// Is this a link or button that has no name yet?
+    needs_name: Option<(IssueKind, usize)>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(&mut self, offset: usize, kind: IssueKind) {
+        self.issues.push(Issue { offset, kind })
+    }
+
+    fn in_foreign(&self) -> bool {
+        self.open.last().map(|open| open.foreign).unwrap_or(false)
+    }
+
+    fn in_hidden(&self) -> bool {
+        self.open.last().map(|open| open.hidden).unwrap_or(false)
+    }
+
+    /// Marks all open links and buttons as having a name.
+    fn found_name(&mut self) {
+        if self.in_hidden() {
+            return
+        }
+        for open in &mut self.open {
+            open.needs_name = None;
+        }
+    }
+
+    fn token(&mut self, offset: usize, token: Token<'a>) {
+        match token {
+            Token::StartTag { name, attrs, self_closing } => {
+                self.start_tag(offset, name, &attrs, self_closing)
+            }
+            Token::EndTag { name } => self.end_tag(name),
+            Token::Text(text) => {
+                if !text.trim().is_empty() {
+                    self.found_name()
+                }
+            }
+            Token::Other | Token::Malformed => { }
+        }
+    }
+
+    fn start_tag(
+        &mut self,
+        offset: usize,
+        name: &'a str,
+        attrs: &[Attribute<'a>],
+        self_closing: bool,
+    ) {
+        let foreign = self.in_foreign()
+            || name.eq_ignore_ascii_case("svg")
+            || name.eq_ignore_ascii_case("math");
+        let hidden = self.in_hidden() || attrs.iter().any(|attr| {
+            attr.name.eq_ignore_ascii_case("aria-hidden")
+                && attr.value() == "true"
+        });
+        let lower = name.to_ascii_lowercase();
+        let lower = lower.as_str();
+
+        for attr in attrs {
+            let attr_name = attr.name.to_ascii_lowercase();
+            if attr_name == "id" {
+                self.ids.insert(attr.value().into_owned());
+            }
+            if let Some(&reference) = ID_REFERENCES.iter().find(|item| {
+                **item == attr_name
+            }) {
+                for id in attr.value().split_ascii_whitespace() {
+                    self.references.push(
+                        (id.into(), reference, attr.offset)
+                    );
+                }
+            }
+        }
+        let has_name = ["aria-label", "aria-labelledby", "title"].iter().any(
+            |name| {
+                attrs.iter().any(|attr| {
+                    attr.name.eq_ignore_ascii_case(name)
+                        && !attr.value().trim().is_empty()
+                })
+            }
+        );
+
+        if has_name && !hidden && !matches!(lower, "a" | "button") {
+            self.found_name()
+        }
+
+        let mut needs_name = None;
+        if !foreign {
+            match lower {
+                "html" if !matches!(
+                    attr_value(attrs, "lang"),
+                    Some(lang) if !lang.trim().is_empty()
+                ) => {
+                    self.report(offset, IssueKind::MissingLang);
+                }
+                "img" => {
+                    // A missing alt attribute is reported by the checker.
+                    if let Some(alt) = attr_value(attrs, "alt") {
+                        if !hidden && !alt.trim().is_empty() {
+                            self.found_name()
+                        }
+                    }
+                }
+                "a" if !has_name && attr_value(attrs, "href").is_some() => {
+                    needs_name = Some((IssueKind::EmptyLink, offset))
+                }
+                "button" if !has_name => {
+                    needs_name = Some((IssueKind::EmptyButton, offset))
+                }
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    let level = lower.as_bytes()[1] - b'0';
+                    if let Some(from) = self.heading {
+                        if level > from + 1 {
+                            self.report(
+                                offset,
+                                IssueKind::SkippedHeadingLevel {
+                                    from, to: level
+                                }
+                            );
+                        }
+                    }
+                    self.heading = Some(level);
+                }
+                _ => { }
+            }
+            if needs_label(lower, attrs) && !has_name && !self.in_label() {
+                match attr_value(attrs, "id") {
+                    Some(id) => {
+                        self.unlabeled.push((id.into(), name, offset))
+                    }
+                    None => {
+                        self.report(
+                            offset, IssueKind::UnlabeledControl(name.into())
+                        )
+                    }
+                }
+            }
+            if lower == "label" {
+                if let Some(target) = attr_value(attrs, "for") {
+                    self.label_targets.insert(target.into());
+                }
+            }
+        }
+
+        if foreign {
+            if self_closing {
+                return
+            }
+        }
+        else if crate::check::is_void(lower) || self_closing {
+            return
+        }
+        self.open.push(Open { name, foreign, hidden, needs_name });
+    }
+
+    fn in_label(&self) -> bool {
+        self.open.iter().any(|open| {
+            !open.foreign && open.name.eq_ignore_ascii_case("label")
+        })
+    }
+
+    fn end_tag(&mut self, name: &'a str) {
+        let pos = self.open.iter().rposition(|open| {
+            if open.foreign {
+                open.name == name
+            }
+            else {
+                open.name.eq_ignore_ascii_case(name)
+            }
+        });
+        if let Some(pos) = pos {
+            while self.open.len() > pos {
+                if let Some(open) = self.open.pop() {
+                    self.close(open);
+                }
+            }
+        }
+    }
+
+    fn close(&mut self, open: Open<'a>) {
+        if let Some((kind, offset)) = open.needs_name {
+            self.report(offset, kind);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Issue> {
+        while let Some(open) = self.open.pop() {
+            self.close(open);
+        }
+        for (id, name, offset) in std::mem::take(&mut self.unlabeled) {
+            if !self.label_targets.contains(&id) {
+                self.report(offset, IssueKind::UnlabeledControl(name.into()));
+            }
+        }
+        for (id, attribute, offset) in std::mem::take(&mut self.references) {
+            if !self.ids.contains(&id) {
+                self.report(offset, IssueKind::MissingReference {
+                    attribute: attribute.into(), id
+                });
+            }
+        }
+        self.issues
+    }
+}
+
+
+//------------ Helpers -------------------------------------------------------
+
+/// The ARIA attributes that refer to other elements by id.
+const ID_REFERENCES: &[&str] = &[
+    "aria-activedescendant", "aria-controls", "aria-describedby",
+    "aria-details", "aria-errormessage", "aria-flowto", "aria-labelledby",
+    "aria-owns",
+];
+
+/// Returns the unescaped value of an attribute if present.
+fn attr_value<'a>(
+    attrs: &[Attribute<'a>], name: &str
+) -> Option<std::borrow::Cow<'a, str>> {
+    attrs.iter().find(|attr| {
+        attr.name.eq_ignore_ascii_case(name)
+    }).map(Attribute::value)
+}
+
+/// Returns whether an element is a form control that needs a label.
+///
+/// Buttons are excluded since their content or value is their label.
+fn needs_label(name: &str, attrs: &[Attribute]) -> bool {
+    match name {
+        "select" | "textarea" => true,
+        "input" => {
+            !attr_value(attrs, "type").is_some_and(|value| {
+                ["button", "hidden", "image", "reset", "submit"].iter().any(
+                    |kind| value.eq_ignore_ascii_case(kind)
+                )
+            })
+        }
+        _ => false
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::raw;
+
+    fn kinds(html: &'static str) -> Vec<IssueKind> {
+        check(raw(html)).into_iter().map(|item| item.kind).collect()
+    }
+
+    fn one(html: &'static str, kind: IssueKind) {
+        assert_eq!(kinds(html), [kind], "{}", html);
+    }
+
+    #[test]
+    fn clean() {
+        assert_eq!(
+            kinds(
+                "<html lang=\"en\"><body><h1>a</h1><h2>b</h2>\
+                 <img src=\"a.png\" alt=\"\"/>\
+                 <a href=\"/\"><img src=\"b.png\" alt=\"Home\"/></a>\
+                 <button aria-label=\"Close\"></button>\
+                 <label>Name <input name=\"n\"/></label>\
+                 <label for=\"q\">Query</label><input id=\"q\" name=\"q\"/>\
+                 <input type=\"hidden\" name=\"h\"/>\
+                 <p id=\"d\">x</p><textarea title=\"t\" \
+                 aria-describedby=\"d\"></textarea>\
+                 <svg><a><text>x</text></a></svg>\
+                 </body></html>"
+            ),
+            []
+        );
+    }
+
+    #[test]
+    fn missing_alt() {
+        one("<img src=\"a.png\"/>", IssueKind::MissingAlt);
+    }
+
+    #[test]
+    fn unlabeled_control() {
+        one("<input name=\"a\"/>", IssueKind::UnlabeledControl(
+            "input".into()
+        ));
+        one("<select id=\"s\"></select>", IssueKind::UnlabeledControl(
+            "select".into()
+        ));
+    }
+
+    #[test]
+    fn skipped_heading_level() {
+        one("<h1>a</h1><h3>b</h3>", IssueKind::SkippedHeadingLevel {
+            from: 1, to: 3
+        });
+    }
+
+    #[test]
+    fn empty_link() {
+        one("<a href=\"/\"><span aria-hidden=\"true\">x</span></a>",
+            IssueKind::EmptyLink
+        );
+        one("<a href=\"/\"><img src=\"a.png\" alt=\"\"/></a>",
+            IssueKind::EmptyLink
+        );
+    }
+
+    #[test]
+    fn empty_button() {
+        one("<button> </button>", IssueKind::EmptyButton);
+    }
+
+    #[test]
+    fn missing_reference() {
+        one(
+            "<div aria-labelledby=\"a b\" id=\"a\">x</div>",
+            IssueKind::MissingReference {
+                attribute: "aria-labelledby".into(), id: "b".into()
+            }
+        );
+    }
+
+    #[test]
+    fn missing_lang() {
+        one("<html></html>", IssueKind::MissingLang);
+        one("<html lang=\" \"></html>", IssueKind::MissingLang);
+    }
+
+    #[test]
+    fn duplicate_id() {
+        one("<p id=\"a\">x</p><p id=\"a\">y</p>", IssueKind::DuplicateId(
+            "a".into()
+        ));
+    }
+
+    #[test]
+    fn label_without_control() {
+        one("<label>x</label>", IssueKind::LabelWithoutControl);
+    }
+
+    #[test]
+    fn offsets_are_sorted() {
+        assert_eq!(
+            check(raw("<h1>a</h1><h3>b</h3><img src=\"a\"/>")),
+            [
+                Issue {
+                    offset: 10,
+                    kind: IssueKind::SkippedHeadingLevel { from: 1, to: 3 }
+                },
+                Issue { offset: 20, kind: IssueKind::MissingAlt },
+            ]
+        );
+    }
+}
@@ -0,0 +1,650 @@
+//! Simple charts rendered as inline SVG.
+//!
+//! The charts in this module turn data series into [`Content`] built from
+//! the elements of the [`svg`][crate::svg] module. Each chart carries a
+//! `title` and optional `desc` element and is marked as an image so that
+//! assistive technology can announce it. Like the `svg` element they are
+//! built from, charts are phrasing content.
+//!
+//! Values that aren't finite, i.e., NaN or infinite values, are skipped.
+
+use crate::core::{Content, Flow, Phrasing, Target};
+use crate::html::attr::{aria, role, Role};
+use crate::svg::{self, attr, PathData};
+use crate::utils::{display, iter};
+
+
+//------------ Series --------------------------------------------------------
+
+/// A named sequence of values.
+#[derive(Clone, Debug)]
+pub struct Series<'a> {
+    label: &'a str,
+    values: &'a [f64],
+    color: Option<&'a str>,
+}
+
+impl<'a> Series<'a> {
+    pub fn new(label: &'a str, values: &'a [f64]) -> Self {
+        Series { label, values, color: None }
+    }
+
+    /// Sets the color used for the series.
+    ///
+    /// If no color is set, the chart picks one from its palette.
+    pub fn color(mut self, color: &'a str) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+
+//------------ BarChart ------------------------------------------------------
+
+/// A chart with a group of vertical bars for each category.
+#[derive(Clone, Debug)]
+pub struct BarChart<'a> {
+    meta: Meta<'a>,
+    categories: &'a [&'a str],
+    series: Vec<Series<'a>>,
+}
+
+impl<'a> BarChart<'a> {
+    pub fn new(title: &'a str, categories: &'a [&'a str]) -> Self {
+        BarChart {
+            meta: Meta::new(title),
+            categories,
+            series: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.meta.description = Some(description);
+        self
+    }
+
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.meta.width = width;
+        self.meta.height = height;
+        self
+    }
+
+    pub fn series(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+}
+
+impl Content for BarChart<'_> {
+    fn render_content(self, target: &mut Target) {
+        let frame = Frame::new(&self.meta, &self.series, true);
+        let slot = frame.plot_width() / self.categories.len().max(1) as f64;
+        let bar = slot * 0.8 / self.series.len().max(1) as f64;
+        let series = &self.series;
+        let categories = self.categories;
+        let bars = iter(series.iter().enumerate().flat_map(|(idx, item)| {
+            let color = series_color(item, idx);
+            item.values.iter().enumerate().take(categories.len()).filter(
+                |(_, value)| value.is_finite()
+            ).map(
+                move |(pos, &value)| {
+                    let x = frame.left + slot * (pos as f64 + 0.1)
+                        + bar * idx as f64;
+                    let (top, bottom) = (frame.y(value), frame.y(0.));
+                    svg::rect::content(
+                        (
+                            attr::x(round(x)),
+                            attr::y(round(top.min(bottom))),
+                            attr::width(display(round(bar))),
+                            attr::height(display(
+                                round((bottom - top).abs())
+                            )),
+                            attr::fill(color),
+                        ),
+                        tooltip(item, categories[pos], value)
+                    )
+                }
+            )
+        }));
+        let labels = iter(categories.iter().enumerate().map(|(pos, label)| {
+            frame.x_label(frame.left + slot * (pos as f64 + 0.5), label)
+        }));
+        frame.render(
+            &self.meta, (frame.axes(), labels, bars, frame.legend(series)),
+            target
+        )
+    }
+}
+
+impl Flow for BarChart<'_> { }
+
+impl Phrasing for BarChart<'_> { }
+
+
+//------------ LineChart -----------------------------------------------------
+
+/// A chart with a line for each series.
+#[derive(Clone, Debug)]
+pub struct LineChart<'a> {
+    meta: Meta<'a>,
+    labels: &'a [&'a str],
+    series: Vec<Series<'a>>,
+}
+
+impl<'a> LineChart<'a> {
+    pub fn new(title: &'a str, labels: &'a [&'a str]) -> Self {
+        LineChart {
+            meta: Meta::new(title),
+            labels,
+            series: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.meta.description = Some(description);
+        self
+    }
+
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.meta.width = width;
+        self.meta.height = height;
+        self
+    }
+
+    pub fn series(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+}
+
+impl Content for LineChart<'_> {
+    fn render_content(self, target: &mut Target) {
+        let frame = Frame::new(&self.meta, &self.series, false);
+        let step = frame.plot_width() / (
+            self.labels.len().saturating_sub(1).max(1) as f64
+        );
+        let x = |pos: usize| frame.left + step * pos as f64;
+        let series = &self.series;
+        let labels = self.labels;
+        let lines = iter(series.iter().enumerate().map(|(idx, item)| {
+            let color = series_color(item, idx);
+            let values = || {
+                item.values.iter().enumerate().take(labels.len()).filter(
+                    |(_, value)| value.is_finite()
+                )
+            };
+            let points = values().map(|(pos, &value)| {
+                (round(x(pos)), round(frame.y(value)))
+            });
+            svg::g(attr::fill(color), (
+                svg::polyline((
+                    attr::points(points),
+                    attr::fill("none"),
+                    attr::stroke(color),
+                    attr::stroke_width(2.),
+                )),
+                iter(values().map(|(pos, &value)| {
+                    svg::circle::content(
+                        (
+                            attr::cx(round(x(pos))),
+                            attr::cy(round(frame.y(value))),
+                            attr::r(3.),
+                        ),
+                        tooltip(item, labels[pos], value)
+                    )
+                }))
+            ))
+        }));
+        let x_labels = iter(labels.iter().enumerate().map(|(pos, label)| {
+            frame.x_label(x(pos), label)
+        }));
+        frame.render(
+            &self.meta, (frame.axes(), x_labels, lines, frame.legend(series)),
+            target
+        )
+    }
+}
+
+impl Flow for LineChart<'_> { }
+
+impl Phrasing for LineChart<'_> { }
+
+
+//------------ Sparkline -----------------------------------------------------
+
+/// A small line chart without axes meant to be used inline with text.
+#[derive(Clone, Debug)]
+pub struct Sparkline<'a> {
+    label: &'a str,
+    values: &'a [f64],
+    width: f64,
+    height: f64,
+    color: &'a str,
+}
+
+impl<'a> Sparkline<'a> {
+    /// Creates a sparkline.
+    ///
+    /// The label is used as the accessible name of the image and should
+    /// describe the data, e.g., “Requests per hour, last 24 hours”.
+    pub fn new(label: &'a str, values: &'a [f64]) -> Self {
+        Sparkline {
+            label, values, width: 100., height: 20., color: "currentColor",
+        }
+    }
+
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn color(mut self, color: &'a str) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Content for Sparkline<'_> {
+    fn render_content(self, target: &mut Target) {
+        let (min, max) = value_range(self.values.iter().copied());
+        let span = if max > min { max - min } else { 1. };
+        let step = self.width / (
+            self.values.len().saturating_sub(1).max(1) as f64
+        );
+        let mut path = PathData::new();
+        let values = self.values.iter().enumerate().filter(|(_, value)| {
+            value.is_finite()
+        });
+        for (pos, value) in values {
+            let x = round(step * pos as f64);
+            let y = round(
+                1. + (self.height - 2.) * (1. - (value - min) / span)
+            );
+            path = if path.is_empty() { path.move_to(x, y) }
+                   else { path.line_to(x, y) };
+        }
+        svg::svg(
+            (
+                attr::view_box(0., 0., self.width, self.height),
+                attr::width(display(self.width)),
+                attr::height(display(self.height)),
+                role(Role::Img),
+                aria::label(self.label),
+            ),
+            (
+                svg::title(self.label),
+                (!path.is_empty()).then(|| {
+                    svg::path(path, (
+                        attr::fill("none"),
+                        attr::stroke(self.color),
+                        attr::stroke_width(1.5),
+                    ))
+                }),
+            )
+        ).render_content(target)
+    }
+}
+
+impl Flow for Sparkline<'_> { }
+
+impl Phrasing for Sparkline<'_> { }
+
+
+//------------ Meta ----------------------------------------------------------
+
+/// The data shared by all charts with axes.
+#[derive(Clone, Debug)]
+struct Meta<'a> {
+    title: &'a str,
+    description: Option<&'a str>,
+    width: f64,
+    height: f64,
+}
+
+impl<'a> Meta<'a> {
+    fn new(title: &'a str) -> Self {
+        Meta { title, description: None, width: 480., height: 240. }
+    }
+}
+
+
+//------------ Frame ---------------------------------------------------------
+
+/// The plot area of a chart and the scale of its values.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    width: f64,
+    height: f64,
+    left: f64,
+    right: f64,
+    top: f64,
+    bottom: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl Frame {
+    const LEFT: f64 = 48.;
+    const RIGHT: f64 = 12.;
+    const TOP: f64 = 12.;
+    const AXIS_LABELS: f64 = 24.;
+    const LEGEND: f64 = 24.;
+
+    fn new(meta: &Meta, series: &[Series], zero: bool) -> Self {
+        let (mut min, mut max) = value_range(
+            series.iter().flat_map(|item| item.values.iter().copied())
+        );
+        if zero {
+            min = min.min(0.);
+            max = max.max(0.);
+        }
+        let step = nice_step((max - min) / 4.);
+        let min = (min / step).floor() * step;
+        let max = ((max / step).ceil() * step).max(min + step);
+        let legend = if series.is_empty() { 0. } else { Self::LEGEND };
+        Frame {
+            width: meta.width,
+            height: meta.height,
+            left: Self::LEFT,
+            right: meta.width - Self::RIGHT,
+            top: Self::TOP,
+            bottom: meta.height - Self::AXIS_LABELS - legend,
+            min, max, step,
+        }
+    }
+
+    fn plot_width(&self) -> f64 {
+        (self.right - self.left).max(0.)
+    }
+
+    /// Returns the vertical position of a value.
+    fn y(&self, value: f64) -> f64 {
+        self.bottom - (self.bottom - self.top) * (
+            (value - self.min) / (self.max - self.min)
+        )
+    }
+
+    fn render(self, meta: &Meta, content: impl Content, target: &mut Target) {
+        svg::svg(
+            (
+                attr::view_box(0., 0., self.width, self.height),
+                attr::width(display(self.width)),
+                attr::height(display(self.height)),
+                role(Role::Img),
+                aria::label(meta.title),
+                attr::font_size("12"),
+            ),
+            (
+                svg::title(meta.title),
+                meta.description.map(svg::desc),
+                content,
+            )
+        ).render_content(target)
+    }
+
+    fn axes(self) -> impl Content {
+        let ticks = ((self.max - self.min) / self.step).round() as usize;
+        svg::g(attr::stroke("currentColor"), (
+            svg::line((
+                attr::x1(self.left), attr::y1(self.top),
+                attr::x2(self.left), attr::y2(self.bottom),
+            )),
+            svg::line((
+                attr::x1(self.left), attr::y1(round(self.y(0.0f64.clamp(
+                    self.min, self.max
+                )))),
+                attr::x2(self.right), attr::y2(round(self.y(0.0f64.clamp(
+                    self.min, self.max
+                )))),
+            )),
+            iter((0..=ticks).map(move |idx| {
+                let value = self.min + self.step * idx as f64;
+                let y = round(self.y(value));
+                (
+                    svg::line((
+                        attr::x1(self.left - 4.), attr::y1(y),
+                        attr::x2(self.left), attr::y2(y),
+                    )),
+                    svg::text(
+                        (
+                            attr::x(self.left - 6.), attr::y(y),
+                            attr::text_anchor("end"),
+                            attr::dominant_baseline("middle"),
+                            attr::stroke("none"),
+                            attr::fill("currentColor"),
+                        ),
+                        display(format_value(value, self.step))
+                    ),
+                )
+            }))
+        ))
+    }
+
+    fn x_label<'a>(&self, x: f64, label: &'a str) -> impl Content + 'a {
+        svg::text(
+            (
+                attr::x(round(x)), attr::y(self.bottom + 16.),
+                attr::text_anchor("middle"),
+                attr::fill("currentColor"),
+            ),
+            label
+        )
+    }
+
+    fn legend<'a>(self, series: &'a [Series<'a>]) -> impl Content + 'a {
+        let y = self.height - Self::LEGEND / 2.;
+        let width = self.plot_width() / series.len().max(1) as f64;
+        iter(series.iter().enumerate().map(move |(idx, item)| {
+            let x = round(self.left + width * idx as f64);
+            svg::g((), (
+                svg::rect((
+                    attr::x(x), attr::y(y - 5.),
+                    attr::width("10"), attr::height("10"),
+                    attr::fill(series_color(item, idx)),
+                )),
+                svg::text(
+                    (
+                        attr::x(x + 14.), attr::y(y),
+                        attr::dominant_baseline("middle"),
+                        attr::fill("currentColor"),
+                    ),
+                    item.label
+                ),
+            ))
+        }))
+    }
+}
+
+
+//------------ Helpers -------------------------------------------------------
+
+const PALETTE: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948",
+    "#b07aa1", "#ff9da7", "#9c755f", "#bab0ac",
+];
+
+fn series_color<'a>(series: &Series<'a>, idx: usize) -> &'a str {
+    series.color.unwrap_or(PALETTE[idx % PALETTE.len()])
+}
+
+/// Returns the smallest and largest finite value or `(0, 1)` if none.
+fn value_range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let mut res: Option<(f64, f64)> = None;
+    for value in values.filter(|value| value.is_finite()) {
+        res = Some(match res {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        });
+    }
+    res.unwrap_or((0., 1.))
+}
+
+/// Returns a step of 1, 2, or 5 times a power of ten close to `raw`.
+fn nice_step(raw: f64) -> f64 {
+    if !(raw.is_finite() && raw > 0.) {
+        return 1.
+    }
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let norm = raw / magnitude;
+    let nice = if norm <= 1. { 1. }
+               else if norm <= 2. { 2. }
+               else if norm <= 5. { 5. }
+               else { 10. };
+    nice * magnitude
+}
+
+/// Creates the `title` of a single data point.
+fn tooltip<'a>(
+    series: &Series<'a>, label: &'a str, value: f64
+) -> impl Content + 'a {
+    let series = series.label;
+    svg::title(display(Tooltip { series, label, value }))
+}
+
+struct Tooltip<'a> {
+    series: &'a str,
+    label: &'a str,
+    value: f64,
+}
+
+impl std::fmt::Display for Tooltip<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f, "{}, {}: {}",
+            self.series, self.label, self.value
+        )
+    }
+}
+
+/// Formats a value with as many decimals as the step requires.
+fn format_value(value: f64, step: f64) -> FormatValue {
+    let decimals = if step > 0. && step < 1. {
+        (-step.log10().floor()) as usize
+    }
+    else {
+        0
+    };
+    FormatValue(value, decimals)
+}
+
+/// A value together with the number of decimals to display.
+struct FormatValue(f64, usize);
+
+impl std::fmt::Display for FormatValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:.*}", self.1, self.0)
+    }
+}
+
+/// Rounds a coordinate to two decimals to keep the output small.
+fn round(value: f64) -> f64 {
+    (value * 100.).round() / 100.
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::strict;
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn empty_series() {
+        let res = render(BarChart::new("t", &["a"]).size(100., 100.));
+        assert!(res.starts_with(
+            "<svg viewBox=\"0 0 100 100\" width=\"100\" height=\"100\" \
+             role=\"img\" aria-label=\"t\" font-size=\"12\">\
+             <title>t</title>"
+        ));
+        assert!(!res.contains("<rect"));
+        assert!(!res.contains("NaN"));
+    }
+
+    #[test]
+    fn negative_values() {
+        let res = render(
+            BarChart::new("t", &["a", "b"]).size(100., 100.)
+                .series(Series::new("s", &[-2., 3.]))
+        );
+        // The zero line sits between the bars.
+        assert!(res.contains(
+            "<line x1=\"48\" y1=\"38.67\" x2=\"88\" y2=\"38.67\"/>"
+        ));
+        assert!(res.contains(
+            "<rect x=\"50\" y=\"38.67\" width=\"16\" height=\"13.33\" \
+             fill=\"#4e79a7\"><title>s, a: -2</title></rect>"
+        ));
+        assert!(res.contains(
+            "<rect x=\"70\" y=\"18.67\" width=\"16\" height=\"20\" \
+             fill=\"#4e79a7\"><title>s, b: 3</title></rect>"
+        ));
+    }
+
+    #[test]
+    fn more_values_than_categories() {
+        let res = render(
+            BarChart::new("t", &["a", "b"])
+                .series(Series::new("s", &[1., 2., 3.]))
+        );
+        assert_eq!(res.matches("<title>s, ").count(), 2);
+        assert!(!res.contains("<title>s, c"));
+
+        let res = render(
+            LineChart::new("t", &["a"])
+                .series(Series::new("s", &[1., 2.]))
+        );
+        assert_eq!(res.matches("<circle").count(), 1);
+    }
+
+    #[test]
+    fn single_point_line() {
+        let res = render(
+            LineChart::new("t", &["a"]).size(100., 100.)
+                .series(Series::new("s", &[2.]))
+        );
+        assert!(res.contains(
+            "<polyline points=\"48,52\" fill=\"none\" \
+             stroke=\"#4e79a7\" stroke-width=\"2\"/>\
+             <circle cx=\"48\" cy=\"52\" r=\"3\">\
+             <title>s, a: 2</title></circle>"
+        ));
+    }
+
+    #[test]
+    fn non_finite_values() {
+        let res = render(
+            LineChart::new("t", &["a", "b", "c"]).size(100., 100.)
+                .series(Series::new("s", &[1., f64::NAN, 3.]))
+        );
+        assert!(res.contains("<polyline points=\"48,52 88,12\""));
+        assert_eq!(res.matches("<circle").count(), 2);
+        assert!(!res.contains("NaN"));
+
+        let res = render(
+            BarChart::new("t", &["a", "b"])
+                .series(Series::new("s", &[f64::INFINITY, 1.]))
+        );
+        assert_eq!(res.matches("<title>s, ").count(), 1);
+        assert!(!res.contains("inf"));
+    }
+
+    #[test]
+    fn sparkline() {
+        assert_eq!(
+            render(strict::span(Sparkline::new(
+                "s", &[1., f64::NAN, 2., f64::INFINITY]
+            ))),
+            "<span><svg viewBox=\"0 0 100 20\" width=\"100\" \
+             height=\"20\" role=\"img\" aria-label=\"s\">\
+             <title>s</title><path d=\"M0 19 L66.67 1\" fill=\"none\" \
+             stroke=\"currentColor\" stroke-width=\"1.5\"/></svg></span>"
+        );
+    }
+}
@@ -0,0 +1,831 @@
+//! Checking rendered HTML for conformance problems.
+//!
+//! The type system catches many mistakes when building content, but not
+//! all of them. The checker in this module walks a rendered [`Target`] and
+//! reports problems it finds as a list of [`Diagnostic`]s. It is meant to
+//! be used in debug builds and tests, e.g., via
+//! `assert_eq!(check::check(content), [])`.
+
+use std::fmt;
+use std::collections::HashSet;
+use crate::core::{Content, Target};
+use crate::parse::{Token, Tokenizer};
+
+
+//------------ check ---------------------------------------------------------
+
+/// Renders the content and checks the result.
+pub fn check(content: impl Content) -> Vec<Diagnostic> {
+    check_target(&content.render())
+}
+
+/// Checks already rendered content.
+pub fn check_target(target: &Target) -> Vec<Diagnostic> {
+    let mut checker = Checker::default();
+    let data = match std::str::from_utf8(target.as_ref()) {
+        Ok(data) => data,
+        Err(err) => {
+            checker.report(err.valid_up_to(), DiagnosticKind::InvalidUtf8);
+            // We still check the part that is fine.
+            std::str::from_utf8(
+                &target.as_ref()[..err.valid_up_to()]
+            ).unwrap_or_default()
+        }
+    };
+    for (offset, token) in Tokenizer::new(data) {
+        checker.token(offset, token);
+    }
+    checker.finish(data.len())
+}
+
+
+//------------ Diagnostic ----------------------------------------------------
+
+/// A problem found in rendered HTML.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The byte offset in the rendered data where the problem was found.
+    pub offset: usize,
+
+    /// What kind of problem was found.
+    pub kind: DiagnosticKind,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.offset, self.kind)
+    }
+}
+
+
+//------------ DiagnosticKind ------------------------------------------------
+
+/// The kinds of problems found by the checker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiagnosticKind {
+    /// An end tag closes an element other than the current one.
+    Misnested {
+        /// The name of the element that is currently open.
+        expected: String,
+
+        /// The name of the element in the end tag.
+        found: String,
+    },
+
+    /// An element is not allowed as a child of its parent.
+    ///
+    /// This covers the rules expressed by the content categories of
+    /// [`core`][crate::core], such as a `div` inside a `p`, a `div`
+    /// directly inside a `ul`, or an `li` outside of a list.
+    NotAllowedIn {
+        /// The name of the parent element.
+        parent: String,
+
+        /// The name of the child element.
+        child: String,
+    },
+
+    /// An end tag for an element that isn't open.
+    UnexpectedEndTag(String),
+
+    /// An element was never closed.
+    Unclosed(String),
+
+    /// A void element such as `br` has content and an end tag.
+    VoidWithContent(String),
+
+    /// A non-void HTML element uses self-closing syntax.
+    SelfClosingNonVoid(String),
+
+    /// The same id is used by more than one element.
+    DuplicateId(String),
+
+    /// An element has the same attribute more than once.
+    ///
+    /// Elements merge duplicate attributes rendered via the attribute
+    /// types of this crate, so this is only found if an attributes
+    /// implementation writes to the target directly.
+    DuplicateAttribute {
+        element: String,
+        attribute: String,
+    },
+
+    /// An `img` element without an `alt` attribute.
+    MissingAlt,
+
+    /// A `label` that doesn't refer to or contain a form control.
+    LabelWithoutControl,
+
+    /// An attribute not allowed on the element.
+    ///
+    /// This includes `aria-` attributes not defined by WAI-ARIA.
+    UnknownAttribute {
+        element: String,
+        attribute: String,
+    },
+
+    /// The rendered data is not valid UTF-8.
+    InvalidUtf8,
+
+    /// Markup that couldn't be parsed.
+    Malformed,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiagnosticKind::Misnested { expected, found } => {
+                write!(f, "end tag '{}' while '{}' is open", found, expected)
+            }
+            DiagnosticKind::NotAllowedIn { parent, child } => {
+                write!(
+                    f, "element '{}' not allowed in '{}'", child, parent
+                )
+            }
+            DiagnosticKind::UnexpectedEndTag(name) => {
+                write!(f, "end tag '{}' without open element", name)
+            }
+            DiagnosticKind::Unclosed(name) => {
+                write!(f, "element '{}' is never closed", name)
+            }
+            DiagnosticKind::VoidWithContent(name) => {
+                write!(f, "void element '{}' has content", name)
+            }
+            DiagnosticKind::SelfClosingNonVoid(name) => {
+                write!(f, "non-void element '{}' is self-closing", name)
+            }
+            DiagnosticKind::DuplicateId(id) => {
+                write!(f, "duplicate id '{}'", id)
+            }
+            DiagnosticKind::DuplicateAttribute { element, attribute } => {
+                write!(
+                    f, "duplicate attribute '{}' on element '{}'",
+                    attribute, element
+                )
+            }
+            DiagnosticKind::MissingAlt => {
+                f.write_str("'img' element without 'alt' attribute")
+            }
+            DiagnosticKind::LabelWithoutControl => {
+                f.write_str("'label' without matching form control")
+            }
+            DiagnosticKind::UnknownAttribute { element, attribute } => {
+                write!(
+                    f, "unknown attribute '{}' on element '{}'",
+                    attribute, element
+                )
+            }
+            DiagnosticKind::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            DiagnosticKind::Malformed => f.write_str("malformed markup"),
+        }
+    }
+}
+
+
+//------------ Checker -------------------------------------------------------
+
+#[derive(Default)]
+struct Checker<'a> {
+    /// The currently open elements.
+    open: Vec<Open<'a>>,
+
+    /// The ids seen so far.
+    ids: HashSet<String>,
+
+    /// The ids of labelable elements.
+    control_ids: HashSet<String>,
+
+    /// The targets of label elements and the offset of the label.
+    label_targets: Vec<(String, usize)>,
+
+    diagnostics: Vec<Diagnostic>,
+}
+
+struct Open<'a> {
+    name: &'a str,
+    offset: usize,
+
+    /// Are we in foreign content, i.e., SVG or MathML?
+    foreign: bool,
+
+    /// Is this a label without a `for` attribute still lacking a control?
+    label_needs_control: bool,
+}
+
+impl<'a> Checker<'a> {
+    fn report(&mut self, offset: usize, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic { offset, kind })
+    }
+
+    fn in_foreign(&self) -> bool {
+        self.open.last().map(|open| open.foreign).unwrap_or(false)
+    }
+
+    fn token(&mut self, offset: usize, token: Token<'a>) {
+        match token {
+            Token::StartTag { name, attrs, self_closing } => {
+                self.start_tag(offset, name, &attrs, self_closing)
+            }
+            Token::EndTag { name } => self.end_tag(offset, name),
+            Token::Malformed => {
+                self.report(offset, DiagnosticKind::Malformed)
+            }
+            Token::Text(_) | Token::Other => { }
+        }
+    }
+
+    fn start_tag(
+        &mut self,
+        offset: usize,
+        name: &'a str,
+        attrs: &[crate::parse::Attribute<'a>],
+        self_closing: bool,
+    ) {
+        let foreign = self.in_foreign()
+            || name.eq_ignore_ascii_case("svg")
+            || name.eq_ignore_ascii_case("math");
+        let lower = name.to_ascii_lowercase();
+        let lower = lower.as_str();
+
+        if !foreign {
+            if let Some(parent) = self.open.last() {
+                let parent_lower = parent.name.to_ascii_lowercase();
+                if !is_allowed_child(&parent_lower, lower) {
+                    self.report(offset, DiagnosticKind::NotAllowedIn {
+                        parent: parent.name.into(),
+                        child: name.into(),
+                    });
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for attr in attrs {
+            if !seen.insert(attr.name.to_ascii_lowercase()) {
+                self.report(attr.offset, DiagnosticKind::DuplicateAttribute {
+                    element: name.into(),
+                    attribute: attr.name.into(),
+                });
+            }
+            if attr.name.eq_ignore_ascii_case("id") {
+                let id = attr.value().into_owned();
+                if !self.ids.insert(id.clone()) {
+                    self.report(attr.offset, DiagnosticKind::DuplicateId(
+                        id.clone()
+                    ));
+                }
+                if is_labelable(lower, attrs) {
+                    self.control_ids.insert(id);
+                }
+            }
+            if !foreign && !is_allowed_attr(lower, attr.name) {
+                self.report(attr.offset, DiagnosticKind::UnknownAttribute {
+                    element: name.into(),
+                    attribute: attr.name.into(),
+                });
+            }
+        }
+
+        if !foreign {
+            if lower == "img"
+                && !attrs.iter().any(|attr| {
+                    attr.name.eq_ignore_ascii_case("alt")
+                })
+            {
+                self.report(offset, DiagnosticKind::MissingAlt);
+            }
+            if is_labelable(lower, attrs) {
+                if let Some(label) = self.open.iter_mut().rev().find(|open| {
+                    open.name.eq_ignore_ascii_case("label")
+                }) {
+                    label.label_needs_control = false;
+                }
+            }
+        }
+
+        let mut label_needs_control = false;
+        if lower == "label" && !foreign {
+            match attrs.iter().find(|attr| {
+                attr.name.eq_ignore_ascii_case("for")
+            }) {
+                Some(attr) => {
+                    self.label_targets.push(
+                        (attr.value().into_owned(), offset)
+                    );
+                }
+                None => label_needs_control = true,
+            }
+        }
+
+        if foreign {
+            if self_closing {
+                return
+            }
+        }
+        else if is_void(lower) {
+            return
+        }
+        else if self_closing {
+            self.report(
+                offset, DiagnosticKind::SelfClosingNonVoid(name.into())
+            );
+            return
+        }
+        self.open.push(Open { name, offset, foreign, label_needs_control });
+    }
+
+    fn end_tag(&mut self, offset: usize, name: &'a str) {
+        if !self.in_foreign() && is_void(&name.to_ascii_lowercase()) {
+            self.report(offset, DiagnosticKind::VoidWithContent(name.into()));
+            return
+        }
+        let pos = self.open.iter().rposition(|open| {
+            if open.foreign {
+                open.name == name
+            }
+            else {
+                open.name.eq_ignore_ascii_case(name)
+            }
+        });
+        let pos = match pos {
+            Some(pos) => pos,
+            None => {
+                self.report(
+                    offset, DiagnosticKind::UnexpectedEndTag(name.into())
+                );
+                return
+            }
+        };
+        if pos + 1 != self.open.len() {
+            let expected = self.open[self.open.len() - 1].name.into();
+            self.report(offset, DiagnosticKind::Misnested {
+                expected, found: name.into()
+            });
+        }
+        while self.open.len() > pos {
+            if let Some(open) = self.open.pop() {
+                self.close(open);
+            }
+        }
+    }
+
+    fn close(&mut self, open: Open<'a>) {
+        if open.label_needs_control {
+            self.report(open.offset, DiagnosticKind::LabelWithoutControl);
+        }
+    }
+
+    fn finish(mut self, end: usize) -> Vec<Diagnostic> {
+        while let Some(open) = self.open.pop() {
+            self.report(end, DiagnosticKind::Unclosed(open.name.into()));
+            self.close(open);
+        }
+        for (target, offset) in std::mem::take(&mut self.label_targets) {
+            if !self.control_ids.contains(&target) {
+                self.report(offset, DiagnosticKind::LabelWithoutControl);
+            }
+        }
+        self.diagnostics.sort_by_key(|item| item.offset);
+        self.diagnostics
+    }
+}
+
+
+//------------ Helpers -------------------------------------------------------
+
+/// Returns whether an element is a void element.
+pub(crate) fn is_void(name: &str) -> bool {
+    matches!(
+        name,
+        "area" | "base" | "br" | "col" | "embed" | "hr" | "img" | "input"
+        | "link" | "meta" | "source" | "track" | "wbr"
+    )
+}
+
+/// Returns whether an event has an event handler attribute.
+///
+/// The event is given in lower case and without the `on` prefix.
+pub(crate) fn is_event(event: &str) -> bool {
+    EVENT_HANDLERS.contains(&event)
+}
+
+/// Returns whether an element can be associated with a label.
+pub(crate) fn is_labelable(
+    name: &str, attrs: &[crate::parse::Attribute]
+) -> bool {
+    match name {
+        "button" | "meter" | "output" | "progress" | "select"
+        | "textarea" => true,
+        "input" => {
+            !attrs.iter().any(|attr| {
+                attr.name.eq_ignore_ascii_case("type")
+                    && attr.value().eq_ignore_ascii_case("hidden")
+            })
+        }
+        _ => false
+    }
+}
+
+/// Returns whether an attribute is allowed on an element.
+///
+/// Attributes on elements we don't know about are always allowed.
+fn is_allowed_attr(element: &str, attr: &str) -> bool {
+    let attr = attr.to_ascii_lowercase();
+    let attr = attr.as_str();
+    if attr.starts_with("data-") {
+        return true
+    }
+    if let Some(event) = attr.strip_prefix("on") {
+        return is_event(event)
+    }
+    if let Some(name) = attr.strip_prefix("aria-") {
+        return ARIA_ATTRS.contains(&name)
+    }
+    if GLOBAL_ATTRS.contains(&attr) {
+        return true
+    }
+    match element_attrs(element) {
+        Some(attrs) => attrs.contains(&attr),
+        None => true,
+    }
+}
+
+/// Returns whether an element may be a child of another element.
+///
+/// This only checks the rules expressed by the content categories of
+/// [`core`][crate::core]. The elements `script` and `template` are allowed
+/// everywhere, as are elements we don't know about.
+fn is_allowed_child(parent: &str, child: &str) -> bool {
+    if matches!(child, "script" | "template") {
+        return true
+    }
+    let parent_allowed = match child {
+        "li" => Some(&["menu", "ol", "ul"][..]),
+        "dd" | "dt" => Some(&["div", "dl"][..]),
+        "tr" => Some(&["table", "tbody", "tfoot", "thead"][..]),
+        "td" | "th" => Some(&["tr"][..]),
+        _ => None
+    };
+    if let Some(parents) = parent_allowed {
+        return parents.contains(&parent)
+    }
+    match parent {
+        "menu" | "ol" | "ul" => false,
+        "dl" => child == "div",
+        "table" => matches!(
+            child, "caption" | "colgroup" | "tbody" | "tfoot" | "thead"
+        ),
+        "tbody" | "tfoot" | "thead" | "tr" => false,
+        "abbr" | "b" | "button" | "cite" | "code" | "dfn" | "em" | "h1"
+        | "h2" | "h3" | "h4" | "h5" | "h6" | "i" | "kbd" | "label"
+        | "legend" | "mark" | "p" | "pre" | "q" | "s" | "samp" | "small"
+        | "span" | "strong" | "sub" | "sup" | "time" | "u" | "var" => {
+            is_phrasing(child)
+        }
+        _ => true
+    }
+}
+
+/// Returns whether an element is phrasing content.
+///
+/// Elements we don't know about, including custom elements, are assumed to
+/// be phrasing content.
+fn is_phrasing(name: &str) -> bool {
+    !matches!(
+        name,
+        "address" | "article" | "aside" | "blockquote" | "body" | "caption"
+        | "col" | "colgroup" | "dd" | "details" | "dialog" | "div" | "dl"
+        | "dt" | "fieldset" | "figcaption" | "figure" | "footer" | "form"
+        | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head" | "header"
+        | "hgroup" | "hr" | "html" | "legend" | "li" | "main" | "menu"
+        | "nav" | "ol" | "optgroup" | "option" | "p" | "pre" | "search"
+        | "section" | "summary" | "table" | "tbody" | "td" | "tfoot" | "th"
+        | "thead" | "title" | "tr" | "ul"
+    )
+}
+
+/// The event handler attributes without the `on` prefix.
+const EVENT_HANDLERS: &[&str] = &[
+    "abort", "afterprint", "animationcancel", "animationend",
+    "animationiteration", "animationstart", "auxclick", "beforeinput",
+    "beforematch", "beforeprint", "beforetoggle", "beforeunload", "blur",
+    "cancel", "canplay", "canplaythrough", "change", "click", "close",
+    "command", "contextlost", "contextmenu", "contextrestored", "copy",
+    "cuechange", "cut", "dblclick", "drag", "dragend", "dragenter",
+    "dragleave", "dragover", "dragstart", "drop", "durationchange",
+    "emptied", "ended", "error", "focus", "formdata", "gotpointercapture",
+    "hashchange", "input", "invalid", "keydown", "keypress", "keyup",
+    "languagechange", "load", "loadeddata", "loadedmetadata", "loadstart",
+    "lostpointercapture", "message", "messageerror", "mousedown",
+    "mouseenter", "mouseleave", "mousemove", "mouseout", "mouseover",
+    "mouseup", "offline", "online", "pagehide", "pagereveal", "pageshow",
+    "pageswap", "paste", "pause", "play", "playing", "pointercancel",
+    "pointerdown", "pointerenter", "pointerleave", "pointermove",
+    "pointerout", "pointerover", "pointerup", "popstate", "progress",
+    "ratechange", "rejectionhandled", "reset", "resize", "scroll",
+    "scrollend", "securitypolicyviolation", "seeked", "seeking", "select",
+    "slotchange", "stalled", "storage", "submit", "suspend", "timeupdate",
+    "toggle", "touchcancel", "touchend", "touchmove", "touchstart",
+    "transitioncancel", "transitionend", "transitionrun",
+    "transitionstart", "unhandledrejection", "unload", "volumechange",
+    "waiting", "wheel",
+];
+
+/// The global attributes, including the RDFa Lite attributes.
+const GLOBAL_ATTRS: &[&str] = &[
+    "accesskey", "autocapitalize", "autocorrect", "autofocus", "class",
+    "contenteditable", "dir", "draggable", "enterkeyhint", "hidden", "id",
+    "inert", "inputmode", "is", "itemid", "itemprop", "itemref",
+    "itemscope", "itemtype", "lang", "nonce", "part", "popover", "prefix",
+    "property", "resource", "role", "slot", "spellcheck", "style",
+    "tabindex", "title", "translate", "typeof", "vocab",
+    "writingsuggestions",
+];
+
+/// The states and properties of WAI-ARIA 1.2 without the `aria-` prefix.
+const ARIA_ATTRS: &[&str] = &[
+    "activedescendant", "atomic", "autocomplete", "busy", "checked",
+    "colcount", "colindex", "colindextext", "colspan", "controls",
+    "current", "describedby", "description", "details", "disabled",
+    "dropeffect", "errormessage", "expanded", "flowto", "grabbed",
+    "haspopup", "hidden", "invalid", "keyshortcuts", "label",
+    "labelledby", "level", "live", "modal", "multiline", "multiselectable",
+    "orientation", "owns", "placeholder", "posinset", "pressed",
+    "readonly", "relevant", "required", "roledescription", "rowcount",
+    "rowindex", "rowindextext", "rowspan", "selected", "setsize", "sort",
+    "valuemax", "valuemin", "valuenow", "valuetext",
+];
+
+/// Returns the element-specific attributes of the known HTML elements.
+fn element_attrs(element: &str) -> Option<&'static [&'static str]> {
+    Some(match element {
+        "a" => &[
+            "download", "href", "hreflang", "ping", "referrerpolicy", "rel",
+            "target", "type",
+        ],
+        "area" => &[
+            "alt", "coords", "download", "href", "ping", "referrerpolicy",
+            "rel", "shape", "target",
+        ],
+        "audio" => &[
+            "autoplay", "controls", "crossorigin", "loop", "muted",
+            "preload", "src",
+        ],
+        "base" => &["href", "target"],
+        "blockquote" | "q" => &["cite"],
+        "button" => &[
+            "command", "commandfor", "disabled", "form", "formaction",
+            "formenctype", "formmethod", "formnovalidate", "formtarget",
+            "name", "popovertarget", "popovertargetaction", "type", "value",
+        ],
+        "canvas" => &["height", "width"],
+        "col" | "colgroup" => &["span"],
+        "data" => &["value"],
+        "del" | "ins" => &["cite", "datetime"],
+        "details" => &["name", "open"],
+        "dialog" => &["closedby", "open"],
+        "embed" => &["height", "src", "type", "width"],
+        "fieldset" => &["disabled", "form", "name"],
+        "form" => &[
+            "accept-charset", "action", "autocomplete", "enctype", "method",
+            "name", "novalidate", "rel", "target",
+        ],
+        "html" => &["xmlns"],
+        "iframe" => &[
+            "allow", "allowfullscreen", "height", "loading", "name",
+            "referrerpolicy", "sandbox", "src", "srcdoc", "width",
+        ],
+        "img" => &[
+            "alt", "crossorigin", "decoding", "fetchpriority", "height",
+            "ismap", "loading", "referrerpolicy", "sizes", "src", "srcset",
+            "usemap", "width",
+        ],
+        "input" => &[
+            "accept", "alpha", "alt", "autocomplete", "checked",
+            "colorspace", "dirname", "disabled", "form", "formaction",
+            "formenctype", "formmethod", "formnovalidate", "formtarget",
+            "height", "list", "max", "maxlength", "min", "minlength",
+            "multiple", "name", "pattern", "placeholder", "popovertarget",
+            "popovertargetaction", "readonly", "required", "size", "src",
+            "step", "type", "value", "width",
+        ],
+        "label" => &["for"],
+        "li" => &["value"],
+        "link" => &[
+            "as", "blocking", "color", "crossorigin", "disabled",
+            "fetchpriority", "href", "hreflang", "imagesizes",
+            "imagesrcset", "integrity", "media", "referrerpolicy", "rel",
+            "sizes", "type",
+        ],
+        "map" => &["name"],
+        "meta" => &["charset", "content", "http-equiv", "media", "name"],
+        "meter" => &["high", "low", "max", "min", "optimum", "value"],
+        "object" => &["data", "form", "height", "name", "type", "width"],
+        "ol" => &["reversed", "start", "type"],
+        "optgroup" => &["disabled", "label"],
+        "option" => &["disabled", "label", "selected", "value"],
+        "output" => &["for", "form", "name"],
+        "progress" => &["max", "value"],
+        "script" => &[
+            "async", "blocking", "crossorigin", "defer", "fetchpriority",
+            "integrity", "nomodule", "referrerpolicy", "src", "type",
+        ],
+        "select" => &[
+            "autocomplete", "disabled", "form", "multiple", "name",
+            "required", "size",
+        ],
+        "slot" => &["name"],
+        "source" => &[
+            "height", "media", "sizes", "src", "srcset", "type", "width",
+        ],
+        "style" => &["blocking", "media"],
+        "td" => &["colspan", "headers", "rowspan"],
+        "template" => &[
+            "shadowrootclonable", "shadowrootdelegatesfocus",
+            "shadowrootmode", "shadowrootserializable",
+        ],
+        "textarea" => &[
+            "autocomplete", "cols", "dirname", "disabled", "form",
+            "maxlength", "minlength", "name", "placeholder", "readonly",
+            "required", "rows", "wrap",
+        ],
+        "th" => &["abbr", "colspan", "headers", "rowspan", "scope"],
+        "time" => &["datetime"],
+        "track" => &["default", "kind", "label", "src", "srclang"],
+        "video" => &[
+            "autoplay", "controls", "crossorigin", "height", "loop",
+            "muted", "playsinline", "poster", "preload", "src", "width",
+        ],
+        "abbr" | "address" | "article" | "aside" | "b" | "bdi" | "bdo"
+        | "body" | "br" | "caption" | "cite" | "code" | "datalist" | "dd"
+        | "dfn" | "div" | "dl" | "dt" | "em" | "figcaption" | "figure"
+        | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head"
+        | "header" | "hgroup" | "hr" | "i" | "kbd" | "legend" | "main"
+        | "mark" | "menu" | "nav" | "noscript" | "p" | "picture" | "pre"
+        | "rp" | "rt" | "ruby" | "s" | "samp" | "search" | "section"
+        | "small" | "span" | "strong" | "sub" | "summary" | "sup"
+        | "table" | "tbody" | "tfoot" | "thead" | "title" | "tr" | "u"
+        | "ul" | "var" | "wbr" => &[],
+        _ => return None
+    })
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::raw;
+
+    fn kinds(html: &'static str) -> Vec<DiagnosticKind> {
+        check(raw(html)).into_iter().map(|item| item.kind).collect()
+    }
+
+    fn one(html: &'static str, kind: DiagnosticKind) {
+        assert_eq!(kinds(html), [kind], "{}", html);
+    }
+
+    #[test]
+    fn clean() {
+        assert_eq!(
+            kinds(
+                "<!DOCTYPE html><html lang=\"en\"><head><title>t</title>\
+                 </head><body><ul><li><p>a<br/>b</p></li></ul>\
+                 <label>Name <input name=\"n\"/></label>\
+                 <table><tr><td>1</td></tr></table>\
+                 <dl><div><dt>a</dt><dd>b</dd></div></dl>\
+                 <svg><g><path d=\"M0 0\"/></g></svg>\
+                 <button onclick=\"f()\" data-x=\"1\">x</button>\
+                 <script>if (a < b) { x = \"</p>\" }</script>\
+                 </body></html>"
+            ),
+            []
+        );
+    }
+
+    #[test]
+    fn misnested() {
+        one("<div><span></div>", DiagnosticKind::Misnested {
+            expected: "span".into(), found: "div".into()
+        });
+    }
+
+    #[test]
+    fn not_allowed_in() {
+        one("<p><div></div></p>", DiagnosticKind::NotAllowedIn {
+            parent: "p".into(), child: "div".into()
+        });
+        one("<ul><div></div></ul>", DiagnosticKind::NotAllowedIn {
+            parent: "ul".into(), child: "div".into()
+        });
+        one("<div><li></li></div>", DiagnosticKind::NotAllowedIn {
+            parent: "div".into(), child: "li".into()
+        });
+        one("<table><td></td></table>", DiagnosticKind::NotAllowedIn {
+            parent: "table".into(), child: "td".into()
+        });
+        one("<h1><p></p></h1>", DiagnosticKind::NotAllowedIn {
+            parent: "h1".into(), child: "p".into()
+        });
+        assert_eq!(kinds("<li>a</li><li>b</li>"), []);
+        assert_eq!(kinds("<p><my-widget></my-widget></p>"), []);
+    }
+
+    #[test]
+    fn unexpected_end_tag() {
+        one("<p></p></div>", DiagnosticKind::UnexpectedEndTag("div".into()));
+    }
+
+    #[test]
+    fn unclosed() {
+        one("<div>", DiagnosticKind::Unclosed("div".into()));
+    }
+
+    #[test]
+    fn void_with_content() {
+        one("<br>x</br>", DiagnosticKind::VoidWithContent("br".into()));
+    }
+
+    #[test]
+    fn self_closing_non_void() {
+        one("<div/>", DiagnosticKind::SelfClosingNonVoid("div".into()));
+    }
+
+    #[test]
+    fn duplicate_id() {
+        one(
+            "<p id=\"a\"></p><p id=\"a\"></p>",
+            DiagnosticKind::DuplicateId("a".into())
+        );
+    }
+
+    #[test]
+    fn duplicate_attribute() {
+        one(
+            "<p id='a' ID=\"b\"></p>",
+            DiagnosticKind::DuplicateAttribute {
+                element: "p".into(), attribute: "ID".into()
+            }
+        );
+        assert_eq!(kinds("<p id='a' class='b'></p>"), []);
+    }
+
+    #[test]
+    fn missing_alt() {
+        one("<img src=\"a.png\">", DiagnosticKind::MissingAlt);
+        assert_eq!(kinds("<img src=\"a.png\" alt=\"\">"), []);
+    }
+
+    #[test]
+    fn label_without_control() {
+        one("<label>x</label>", DiagnosticKind::LabelWithoutControl);
+        one(
+            "<label for=\"a\">x</label><input id=\"b\">",
+            DiagnosticKind::LabelWithoutControl
+        );
+        one(
+            "<label for=\"a\">x</label><input type=\"hidden\" id=\"a\">",
+            DiagnosticKind::LabelWithoutControl
+        );
+        assert_eq!(
+            kinds("<label for=\"a\">x</label><input id=\"a\">"), []
+        );
+    }
+
+    #[test]
+    fn unknown_attribute() {
+        one("<p href=\"a\"></p>", DiagnosticKind::UnknownAttribute {
+            element: "p".into(), attribute: "href".into()
+        });
+        one("<p aria-foo=\"a\"></p>", DiagnosticKind::UnknownAttribute {
+            element: "p".into(), attribute: "aria-foo".into()
+        });
+        one("<p onion=\"a\"></p>", DiagnosticKind::UnknownAttribute {
+            element: "p".into(), attribute: "onion".into()
+        });
+        assert_eq!(kinds("<p onclick=\"a\" aria-label=\"b\"></p>"), []);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut target = Target::new();
+        target.append_slice(b"<p>a\xffb</p>");
+        assert_eq!(
+            check_target(&target),
+            [
+                Diagnostic { offset: 4, kind: DiagnosticKind::InvalidUtf8 },
+                Diagnostic {
+                    offset: 4, kind: DiagnosticKind::Unclosed("p".into())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        one("<p class=\"a>", DiagnosticKind::Malformed);
+        one("</", DiagnosticKind::Malformed);
+        one("</>", DiagnosticKind::Malformed);
+    }
+}
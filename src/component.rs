@@ -0,0 +1,221 @@
+//! Reusable components.
+//!
+//! A component is a type holding the props of a reusable piece of content
+//! such as a card, a modal, or a navigation bar. By implementing the
+//! [`Component`] trait, it can be given extra attributes, classes, and
+//! children by whoever uses it. These are collected in an [`Instance`]
+//! which can then be used as content of the component’s [`Category`].
+//!
+//! A component should add the extra attributes to its root element after
+//! its own attributes. Because elements merge duplicate attributes, classes
+//! given to an instance are then added to the component’s own classes and
+//! other attributes given to the instance replace those set by the
+//! component.
+
+use crate::core::{Attr, Attributes, Content, Flow, Phrasing, Target};
+
+
+//------------ Component -----------------------------------------------------
+
+/// A type that can be rendered as a reusable component.
+pub trait Component: Sized {
+    /// The category of the content rendered by the component.
+    ///
+    /// This is either [`FlowComponent`] or [`PhrasingComponent`].
+    type Category: Category;
+
+    /// Renders the component.
+    ///
+    /// The `attrs` should be rendered after the component’s own attributes
+    /// of its root element. The `children` should be placed wherever the
+    /// component keeps its main content. Both are empty unless they have
+    /// been given via an [`Instance`].
+    fn render_component(
+        self,
+        attrs: impl Attributes,
+        children: impl Flow,
+        target: &mut Target,
+    );
+
+    /// Returns an instance of the component without extra attributes.
+    fn instance(self) -> Instance<Self, (), ()> {
+        Instance::new(self)
+    }
+
+    /// Returns an instance of the component with extra attributes.
+    fn attrs<A: Attributes>(self, attrs: A) -> Instance<Self, ((), A), ()> {
+        Instance::new(self).attrs(attrs)
+    }
+
+    /// Returns an instance of the component with extra classes.
+    fn class(
+        self, class: &str
+    ) -> Instance<Self, ((), Attr<&'static str, &str>), ()> {
+        Instance::new(self).class(class)
+    }
+
+    /// Returns an instance of the component with children.
+    fn children<C: Flow>(self, children: C) -> Instance<Self, (), ((), C)> {
+        Instance::new(self).children(children)
+    }
+}
+
+
+//------------ Category ------------------------------------------------------
+
+/// The category of the content rendered by a component.
+pub trait Category { }
+
+/// A category of components that render phrasing content.
+pub trait PhrasingCategory: Category { }
+
+/// The category of components that render flow content.
+pub enum FlowComponent { }
+
+impl Category for FlowComponent { }
+
+/// The category of components that render phrasing content.
+pub enum PhrasingComponent { }
+
+impl Category for PhrasingComponent { }
+impl PhrasingCategory for PhrasingComponent { }
+
+
+//------------ Instance ------------------------------------------------------
+
+/// A component with the extra attributes and children given to it.
+pub struct Instance<P, A, C> {
+    props: P,
+    attrs: A,
+    children: C,
+}
+
+impl<P> Instance<P, (), ()> {
+    pub fn new(props: P) -> Self {
+        Instance { props, attrs: (), children: () }
+    }
+}
+
+impl<P, A, C> Instance<P, A, C> {
+    /// Adds extra attributes.
+    ///
+    /// Attributes added later replace those added earlier, except for
+    /// `class` and `style` which are merged.
+    pub fn attrs<B: Attributes>(self, attrs: B) -> Instance<P, (A, B), C> {
+        Instance {
+            props: self.props,
+            attrs: (self.attrs, attrs),
+            children: self.children,
+        }
+    }
+
+    /// Adds the tokens of `class` to the classes of the root element.
+    pub fn class(
+        self, class: &str
+    ) -> Instance<P, (A, Attr<&'static str, &str>), C> {
+        Instance {
+            props: self.props,
+            attrs: (self.attrs, Attr::new("class", class)),
+            children: self.children,
+        }
+    }
+
+    /// Adds children after those added earlier.
+    pub fn children<D: Flow>(self, children: D) -> Instance<P, A, (C, D)> {
+        Instance {
+            props: self.props,
+            attrs: self.attrs,
+            children: (self.children, children),
+        }
+    }
+}
+
+impl<P, A, C> Content for Instance<P, A, C>
+where P: Component, A: Attributes, C: Flow {
+    fn render_content(self, target: &mut Target) {
+        self.props.render_component(self.attrs, self.children, target)
+    }
+}
+
+impl<P, A, C> Flow for Instance<P, A, C>
+where P: Component, A: Attributes, C: Flow { }
+
+impl<P, A, C> Phrasing for Instance<P, A, C>
+where
+    P: Component, P::Category: PhrasingCategory,
+    A: Attributes, C: Flow,
+{ }
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::{attr, strict};
+
+    struct Card(&'static str);
+
+    impl Component for Card {
+        type Category = FlowComponent;
+
+        fn render_component(
+            self,
+            attrs: impl Attributes,
+            children: impl Flow,
+            target: &mut Target,
+        ) {
+            strict::div::attrs(
+                (attr::class("card"), attr::id(self.0), attrs),
+                (strict::h2(self.0), children),
+            ).render_content(target)
+        }
+    }
+
+    struct Badge(&'static str);
+
+    impl Component for Badge {
+        type Category = PhrasingComponent;
+
+        fn render_component(
+            self,
+            attrs: impl Attributes,
+            _children: impl Flow,
+            target: &mut Target,
+        ) {
+            strict::span::attrs(
+                (attr::class("badge"), attrs), self.0
+            ).render_content(target)
+        }
+    }
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn class_merging() {
+        assert_eq!(
+            render(Card("a").class("wide").class("dark")),
+            "<div class=\"card wide dark\" id=\"a\"><h2>a</h2></div>"
+        );
+    }
+
+    #[test]
+    fn attribute_override() {
+        assert_eq!(
+            render(
+                Card("a").attrs(attr::id("b")).children(strict::p("x"))
+            ),
+            "<div class=\"card\" id=\"b\"><h2>a</h2><p>x</p></div>"
+        );
+    }
+
+    #[test]
+    fn phrasing_component() {
+        assert_eq!(
+            render(strict::p(("new ", Badge("1").attrs(attr::title("t"))))),
+            "<p>new <span class=\"badge\" title=\"t\">1</span></p>"
+        );
+    }
+}
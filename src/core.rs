@@ -1,6 +1,11 @@
-use std::fmt;
+use std::{error, fmt, mem};
+use std::ops::Range;
+use std::collections::{BTreeMap, HashMap};
 use super::escape;
 
+#[cfg(feature = "derive")]
+pub use htmlfn_derive::Content;
+
 
 //============ Traits ========================================================
 
@@ -21,6 +26,7 @@ pub trait Content {
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a> Content for &'a str {
     fn render_content(self, target: &mut Target) {
         escape::render_pcdata(self, target)
@@ -61,16 +67,56 @@ impl Content for Target {
 /// of an attribute of an HTML element. The trait is necessary because we
 /// might want to construct the names of data attributes from prefix and
 /// suffix.
+///
+/// Names are checked when the attribute is rendered. An attribute whose
+/// name isn’t valid according to [`is_valid_attr_name`] is left out, so
+/// that names built at runtime cannot inject markup.
 pub trait AttributeName {
     fn render_attr_name(self, target: &mut Target);
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a> AttributeName for &'a str {
     fn render_attr_name(self, target: &mut Target) {
         target.append_slice(self.as_bytes())
     }
 }
 
+impl AttributeName for String {
+    fn render_attr_name(self, target: &mut Target) {
+        self.as_str().render_attr_name(target)
+    }
+}
+
+/// Returns whether a string is a valid HTML attribute name.
+///
+/// A valid name is not empty and contains neither white space, control
+/// characters, nor any of `"`, `'`, `>`, `/`, and `=`.
+pub fn is_valid_attr_name(name: &str) -> bool {
+    !name.is_empty() && !name.chars().any(|ch| {
+        ch.is_whitespace() || ch.is_control()
+            || matches!(ch, '"' | '\'' | '>' | '/' | '=')
+    })
+}
+
+/// Renders an attribute name, checking that it is valid.
+///
+/// If the name is invalid, everything rendered since `start` is removed
+/// again and `false` is returned.
+fn render_checked_name(
+    name: impl AttributeName, start: usize, target: &mut Target
+) -> bool {
+    let name_start = target.len();
+    name.render_attr_name(target);
+    let valid = std::str::from_utf8(
+        &target.buf[name_start..]
+    ).is_ok_and(is_valid_attr_name);
+    if !valid {
+        target.buf.truncate(start);
+    }
+    valid
+}
+
 
 //------------ AttributeValue ------------------------------------------------
 
@@ -82,12 +128,19 @@ pub trait AttributeValue {
     fn render_attr_value(self, target: &mut Target);
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a> AttributeValue for &'a str {
     fn render_attr_value(self, target: &mut Target) {
         escape::render_attr(self, target)
     }
 }
 
+impl AttributeValue for String {
+    fn render_attr_value(self, target: &mut Target) {
+        self.as_str().render_attr_value(target)
+    }
+}
+
 impl<'a> AttributeValue for fmt::Arguments<'a> {
     fn render_attr_value(self, target: &mut Target) {
         escape::format_attr(self, target)
@@ -100,6 +153,15 @@ impl<'a> AttributeValue for fmt::Arguments<'a> {
 ///
 /// This trait represents a, possibly empty, sequence of HTML element
 /// attributes.
+///
+/// The sequence may contain the same attribute more than once. When
+/// rendering an element, such duplicates are merged: the tokens of `class`
+/// attributes are concatenated, the declarations of `style` attributes are
+/// joined, and the last value wins for all other attributes. Merging only
+/// considers attributes rendered via [`Attr`] and [`BoolAttr`], which all
+/// attribute types of this crate do. Anything an implementation writes to
+/// the target directly is kept as is. Use
+/// [`check`][crate::check::check] to find duplicates in rendered HTML.
 pub trait Attributes {
     /// Renders the attributes.
     ///
@@ -108,6 +170,33 @@ pub trait Attributes {
     fn render_attrs(self, target: &mut Target);
 }
 
+impl<K: AttributeName, V: AttributeValue> Attributes for Vec<(K, V)> {
+    fn render_attrs(self, target: &mut Target) {
+        for (key, value) in self {
+            Attr::new(key, value).render_attrs(target)
+        }
+    }
+}
+
+impl<K, V> Attributes for BTreeMap<K, V>
+where K: AttributeName, V: AttributeValue {
+    fn render_attrs(self, target: &mut Target) {
+        for (key, value) in self {
+            Attr::new(key, value).render_attrs(target)
+        }
+    }
+}
+
+/// Renders the attributes sorted by key so the output is deterministic.
+impl<K, V, S> Attributes for HashMap<K, V, S>
+where K: AttributeName + Ord, V: AttributeValue {
+    fn render_attrs(self, target: &mut Target) {
+        let mut attrs: Vec<_> = self.into_iter().collect();
+        attrs.sort_by(|left, right| left.0.cmp(&right.0));
+        attrs.render_attrs(target)
+    }
+}
+
 
 //------------ Tokens --------------------------------------------------------
 
@@ -149,14 +238,63 @@ impl<'a> Tokens<'a> for Option<&'a str> {
 pub trait Text: Content {
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a> Text for &'a str {
 }
 
+impl Text for String { }
+
 impl Text for () { }
 
 impl<T: Text> Text for Option<T> { }
 
 
+//------------ Content Categories --------------------------------------------
+
+/// Flow content.
+///
+/// This is the category of most elements used in the body of a document.
+/// Like all content categories, it is a marker trait that allows the
+/// strict builders in [`html::strict`][crate::html::strict] to refuse
+/// invalid children at compile time.
+pub trait Flow: Content { }
+
+/// Phrasing content.
+///
+/// This is the text of a document and the elements marking it up.
+pub trait Phrasing: Flow { }
+
+/// The content of a `ul`, `ol`, or `menu` element, i.e., `li` elements.
+pub trait ListItems: Content { }
+
+/// The content of a `dl` element, i.e., `dt` and `dd` elements.
+pub trait DescriptionItems: Content { }
+
+/// The content of a `table` element, i.e., `tr` elements.
+pub trait TableRows: Content { }
+
+/// The content of a `tr` element, i.e., `td` and `th` elements.
+pub trait TableCells: Content { }
+
+/// The content of a `select` element, i.e., `option` and `optgroup`
+/// elements.
+pub trait SelectItems: Content { }
+
+/// The content of an `optgroup` or `datalist` element, i.e., `option`
+/// elements.
+pub trait OptionItems: Content { }
+
+/// The sources of a media element, i.e., `source` and `track` elements.
+pub trait MediaSources: Content { }
+
+impl Flow for &str { }
+impl Phrasing for &str { }
+impl Flow for String { }
+impl Phrasing for String { }
+impl Flow for fmt::Arguments<'_> { }
+impl Phrasing for fmt::Arguments<'_> { }
+
+
 //============ Types =========================================================
 
 //------------ Target --------------------------------------------------------
@@ -165,6 +303,15 @@ impl<T: Text> Text for Option<T> { }
 #[derive(Default)]
 pub struct Target {
     buf: Vec<u8>,
+    bool_style: BoolStyle,
+    link_policy: LinkPolicy,
+
+    /// The attributes rendered for the current element.
+    ///
+    /// Attributes are only recorded while `collect_attrs` is set, i.e.,
+    /// while an element renders its attributes.
+    attr_spans: Vec<AttrSpan>,
+    collect_attrs: bool,
 }
 
 impl Target {
@@ -172,6 +319,24 @@ impl Target {
         Self::default()
     }
 
+    /// Creates a new target using the given style for boolean attributes.
+    pub fn with_bool_style(bool_style: BoolStyle) -> Self {
+        Target { bool_style, ..Default::default() }
+    }
+
+    pub fn bool_style(&self) -> BoolStyle {
+        self.bool_style
+    }
+
+    pub fn link_policy(&self) -> &LinkPolicy {
+        &self.link_policy
+    }
+
+    /// Sets the policy for links rendered into the target from now on.
+    pub fn set_link_policy(&mut self, policy: LinkPolicy) {
+        self.link_policy = policy
+    }
+
     pub fn len(&self) -> usize {
         self.buf.len()
     }
@@ -187,6 +352,19 @@ impl Target {
     fn write_fmt(&mut self, args: fmt::Arguments<'_>) {
         fmt::write(self, args).unwrap();
     }
+
+    /// Records an attribute rendered into the target.
+    fn record_attr(
+        &mut self,
+        start: usize,
+        name: Range<usize>,
+        value: Option<Range<usize>>,
+    ) {
+        if self.collect_attrs {
+            let end = self.len();
+            self.attr_spans.push(AttrSpan { start, name, value, end })
+        }
+    }
 }
 
 impl fmt::Write for Target {
@@ -210,6 +388,67 @@ impl Into<hyper::body::Body> for Target {
 }
 
 
+//------------ BoolStyle -----------------------------------------------------
+
+/// How boolean attributes are rendered.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BoolStyle {
+    /// Only the attribute name is rendered, e.g., `checked`.
+    #[default]
+    Minimized,
+
+    /// The name is repeated as the value, e.g., `checked="checked"`.
+    Expanded,
+}
+
+
+//------------ LinkPolicy ----------------------------------------------------
+
+/// How links opening in a new browsing context are rendered.
+///
+/// A page opened via `target="_blank"` gets access to the page that
+/// opened it unless the link has `rel="noopener"`. The policy is applied
+/// to every `a` and `area` element with a `_blank` target as well as to
+/// every `form` element, using its `action`, no matter which builder or
+/// attributes created it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum LinkPolicy {
+    /// Links are rendered as given.
+    #[default]
+    Unchanged,
+
+    /// External links with a `_blank` target get `rel="noopener noreferrer"`.
+    ///
+    /// The two tokens are added to any `rel` attribute the link already
+    /// has.
+    ///
+    /// The URL is the address of the page being rendered. Relative links
+    /// are resolved against it and a link is external if the resulting
+    /// URL has a different origin.
+    ProtectExternal(url::Url),
+}
+
+impl LinkPolicy {
+    /// Creates a policy protecting links external to the given page.
+    pub fn protect_external(page: &str) -> Result<Self, url::ParseError> {
+        url::Url::parse(page).map(LinkPolicy::ProtectExternal)
+    }
+
+    /// Returns whether a `_blank` link needs protection under the policy.
+    pub fn needs_protection(&self, href: &str) -> bool {
+        match self {
+            LinkPolicy::Unchanged => false,
+            LinkPolicy::ProtectExternal(page) => {
+                match page.join(href) {
+                    Ok(url) => url.origin() != page.origin(),
+                    Err(_) => true,
+                }
+            }
+        }
+    }
+}
+
+
 //------------ Element -------------------------------------------------------
 
 /// An HTML element.
@@ -228,7 +467,7 @@ impl<T, A, C> Element<T, A, C> {
 impl<T: AsRef<str>, A: Attributes, C: Content> Content for Element<T, A, C> {
     fn render_content(self, target: &mut Target) {
         write!(target, "<{}", self.tag.as_ref());
-        self.attrs.render_attrs(target);
+        render_element_attrs(self.tag.as_ref(), self.attrs, target);
         write!(target, ">");
         self.content.render_content(target);
         write!(target, "</{}>", self.tag.as_ref())
@@ -253,7 +492,7 @@ impl<T: AsRef<str>, A: Attributes> EmptyElement<T, A> {
 impl<T: AsRef<str>, A: Attributes> Content for EmptyElement<T, A> {
     fn render_content(self, target: &mut Target) {
         write!(target, "<{}", self.tag.as_ref());
-        self.attrs.render_attrs(target);
+        render_element_attrs(self.tag.as_ref(), self.attrs, target);
         write!(target, "/>");
     }
 }
@@ -277,7 +516,7 @@ impl<T, A, C> TextElement<T, A, C> {
 impl<T: AsRef<str>, A: Attributes, C: Text> Content for TextElement<T, A, C> {
     fn render_content(self, target: &mut Target) {
         write!(target, "<{}", self.tag.as_ref());
-        self.attrs.render_attrs(target);
+        render_element_attrs(self.tag.as_ref(), self.attrs, target);
         write!(target, ">");
         self.content.render_content(target);
         write!(target, "</{}>", self.tag.as_ref())
@@ -302,11 +541,413 @@ impl<K, V> Attr<K, V> {
 
 impl<K: AttributeName, V: AttributeValue> Attributes for Attr<K, V> {
     fn render_attrs(self, target: &mut Target) {
+        let start = target.len();
         target.append_slice(b" ");
-        self.key.render_attr_name(target);
+        if !render_checked_name(self.key, start, target) {
+            return
+        }
+        let name = start + 1..target.len();
         target.append_slice(b"=\"");
+        let value_start = target.len();
         self.value.render_attr_value(target);
+        let value = value_start..target.len();
         target.append_slice(b"\"");
+        target.record_attr(start, name, Some(value));
+    }
+}
+
+
+//------------ AttrList ------------------------------------------------------
+
+/// A list of attributes assembled at runtime.
+///
+/// The list never contains the same attribute twice. Inserting an
+/// attribute that is already present merges the values the same way
+/// elements merge duplicate attributes: class tokens are added to the
+/// existing ones, style declarations are joined, and the new value replaces
+/// the old one for everything else. Attributes are rendered in the order
+/// they were first inserted.
+///
+/// The list only accepts valid attribute names as determined by
+/// [`is_valid_attr_name`].
+#[derive(Clone, Debug, Default)]
+pub struct AttrList {
+    attrs: Vec<(String, String)>,
+}
+
+impl AttrList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.attrs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attrs.is_empty()
+    }
+
+    /// Returns the value of the attribute with the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attrs.iter().find_map(|(name, value)| {
+            (name == key).then_some(value.as_str())
+        })
+    }
+
+    /// Inserts an attribute, merging it with an existing one.
+    ///
+    /// Returns an error and leaves the list unchanged if `key` is not a
+    /// valid attribute name.
+    pub fn insert(
+        &mut self, key: impl Into<String>, value: impl Into<String>
+    ) -> Result<(), InvalidAttributeName> {
+        let key = key.into();
+        if !is_valid_attr_name(&key) {
+            return Err(InvalidAttributeName(key))
+        }
+        self.insert_valid(key, value.into());
+        Ok(())
+    }
+
+    /// Adds the tokens of `class` to the class attribute.
+    pub fn add_class(&mut self, class: &str) {
+        self.insert_valid("class".into(), class.into())
+    }
+
+    fn insert_valid(&mut self, key: String, value: String) {
+        match self.attrs.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, old)) => {
+                let mut merged = mem::take(old).into_bytes();
+                merge_attr_value(
+                    key.as_bytes(), &mut merged, value.as_bytes()
+                );
+                // Values are only split at ASCII characters when merging.
+                *old = String::from_utf8(merged).expect("merged UTF-8");
+            }
+            None => self.attrs.push((key, value)),
+        }
+    }
+
+    /// Removes the attribute with the given key and returns its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let idx = self.attrs.iter().position(|(name, _)| name == key)?;
+        Some(self.attrs.remove(idx).1)
+    }
+}
+
+/// Extends the list with attributes.
+///
+/// Attributes with invalid names are skipped.
+impl<K: Into<String>, V: Into<String>> Extend<(K, V)> for AttrList {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            let _ = self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for AttrList {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl Attributes for AttrList {
+    fn render_attrs(self, target: &mut Target) {
+        self.attrs.render_attrs(target)
+    }
+}
+
+impl Attributes for &AttrList {
+    fn render_attrs(self, target: &mut Target) {
+        for (key, value) in &self.attrs {
+            Attr::new(key.as_str(), value.as_str()).render_attrs(target)
+        }
+    }
+}
+
+/// Merges `new` into the value `old` of a duplicate attribute `key`.
+///
+/// The tokens of the `class` and `rel` attributes are concatenated, the
+/// declarations of the `style` attribute are joined, and the new value
+/// replaces the old one for all other attributes.
+fn merge_attr_value(key: &[u8], old: &mut Vec<u8>, new: &[u8]) {
+    if key.eq_ignore_ascii_case(b"class") || key.eq_ignore_ascii_case(b"rel")
+    {
+        for token in new.split(u8::is_ascii_whitespace) {
+            let mut tokens = old.split(u8::is_ascii_whitespace);
+            if token.is_empty() || tokens.any(|item| item == token) {
+                continue
+            }
+            if !old.is_empty() {
+                old.push(b' ');
+            }
+            old.extend_from_slice(token);
+        }
+    }
+    else if key.eq_ignore_ascii_case(b"style") {
+        while old.last().is_some_and(|&ch| {
+            ch.is_ascii_whitespace() || ch == b';'
+        }) {
+            old.pop();
+        }
+        let start = new.iter().position(|ch| !ch.is_ascii_whitespace());
+        let end = new.iter().rposition(|ch| !ch.is_ascii_whitespace());
+        if let (Some(start), Some(end)) = (start, end) {
+            if !old.is_empty() {
+                old.extend_from_slice(b"; ");
+            }
+            old.extend_from_slice(&new[start..=end]);
+        }
+    }
+    else {
+        old.clear();
+        old.extend_from_slice(new);
+    }
+}
+
+
+//------------ Attribute Merging ---------------------------------------------
+
+/// The location of an attribute rendered into a target.
+struct AttrSpan {
+    /// The start of the attribute including the leading white space.
+    start: usize,
+
+    /// The attribute name.
+    name: Range<usize>,
+
+    /// The attribute value without the quotes if there is one.
+    value: Option<Range<usize>>,
+
+    /// The end of the attribute.
+    end: usize,
+}
+
+/// Renders the attributes of an element, merging duplicates.
+///
+/// The target records the attributes rendered via [`Attr`] and
+/// [`BoolAttr`]. If this reveals duplicates, only the attributes involved
+/// are rewritten following the rules of `merge_attr_value`. Everything
+/// else stays as it was rendered.
+///
+/// The recorded attributes are also used to apply the target’s
+/// [`LinkPolicy`] to the element.
+fn render_element_attrs(
+    tag: &str, attrs: impl Attributes, target: &mut Target
+) {
+    let start = target.len();
+    target.collect_attrs = true;
+    attrs.render_attrs(target);
+    if needs_link_protection(tag, target) {
+        Attr::new("rel", "noopener noreferrer").render_attrs(target);
+    }
+    target.collect_attrs = false;
+
+    let mut spans = mem::take(&mut target.attr_spans);
+    if spans.len() > 1 {
+        let buf = &target.buf;
+        spans.sort_unstable_by(|left, right| {
+            let left_name = buf[left.name.clone()].iter();
+            let right_name = buf[right.name.clone()].iter();
+            left_name.map(u8::to_ascii_lowercase).cmp(
+                right_name.map(u8::to_ascii_lowercase)
+            ).then(left.start.cmp(&right.start))
+        });
+        if spans.windows(2).any(|pair| same_attr_name(buf, pair)) {
+            merge_duplicates(&spans, start, target);
+        }
+    }
+    spans.clear();
+    target.attr_spans = spans;
+}
+
+/// Returns whether the link policy applies to the element just rendered.
+///
+/// This is the case for `a`, `area`, and `form` elements opening in a new
+/// browsing context if the policy considers their URL external.
+fn needs_link_protection(tag: &str, target: &Target) -> bool {
+    if target.link_policy == LinkPolicy::Unchanged {
+        return false
+    }
+    let url_attr: &[u8] = if tag.eq_ignore_ascii_case("form") {
+        b"action"
+    }
+    else if tag.eq_ignore_ascii_case("a") || tag.eq_ignore_ascii_case("area")
+    {
+        b"href"
+    }
+    else {
+        return false
+    };
+
+    // The last attribute of a name wins when merging, so we look for
+    // that.
+    let value = |name: &[u8]| {
+        target.attr_spans.iter().rev().find(|span| {
+            target.buf[span.name.clone()].eq_ignore_ascii_case(name)
+        }).map(|span| match span.value.clone() {
+            Some(value) => {
+                String::from_utf8_lossy(&target.buf[value]).into_owned()
+            }
+            None => String::new(),
+        })
+    };
+    if !value(b"target").is_some_and(|value| {
+        value.eq_ignore_ascii_case("_blank")
+    }) {
+        return false
+    }
+    match value(url_attr) {
+        Some(url) => {
+            target.link_policy.needs_protection(
+                &crate::parse::unescape(&url)
+            )
+        }
+        // A form without an action submits to the page itself.
+        None => false,
+    }
+}
+
+/// Returns whether the two spans are for attributes of the same name.
+fn same_attr_name(buf: &[u8], pair: &[AttrSpan]) -> bool {
+    buf[pair[0].name.clone()].eq_ignore_ascii_case(&buf[pair[1].name.clone()])
+}
+
+/// Rewrites the attributes rendered since `start`, merging duplicates.
+///
+/// The spans must be sorted by name and then by position.
+fn merge_duplicates(spans: &[AttrSpan], start: usize, target: &mut Target) {
+    // The replacements for duplicate attributes as their start, end, and
+    // new data. The first attribute of a name is replaced by the merged
+    // attribute, all others are removed.
+    let mut edits = Vec::new();
+    let buf = &target.buf;
+    let mut idx = 0;
+    while idx < spans.len() {
+        let mut end = idx + 1;
+        while end < spans.len()
+            && same_attr_name(buf, &spans[end - 1..=end])
+        {
+            end += 1;
+        }
+        let group = &spans[idx..end];
+        idx = end;
+        if group.len() == 1 {
+            continue
+        }
+        let first = &group[0];
+        let last = &group[group.len() - 1];
+        let name = &buf[first.name.clone()];
+        let data = if name.eq_ignore_ascii_case(b"class")
+            || name.eq_ignore_ascii_case(b"rel")
+            || name.eq_ignore_ascii_case(b"style")
+        {
+            let mut value = Vec::new();
+            for span in group {
+                if let Some(new) = span.value.clone() {
+                    merge_attr_value(name, &mut value, &buf[new]);
+                }
+            }
+            let mut data = Vec::with_capacity(name.len() + value.len() + 4);
+            data.push(b' ');
+            data.extend_from_slice(name);
+            data.extend_from_slice(b"=\"");
+            data.extend_from_slice(&value);
+            data.push(b'"');
+            data
+        }
+        else {
+            buf[last.start..last.end].to_vec()
+        };
+        edits.push((first.start, first.end, Some(data)));
+        edits.extend(
+            group[1..].iter().map(|span| (span.start, span.end, None))
+        );
+    }
+    edits.sort_unstable_by_key(|edit| edit.0);
+
+    let section = target.buf.split_off(start);
+    let mut pos = start;
+    for (edit_start, edit_end, data) in edits {
+        target.append_slice(&section[pos - start..edit_start - start]);
+        if let Some(data) = data {
+            target.append_slice(&data);
+        }
+        pos = edit_end;
+    }
+    target.append_slice(&section[pos - start..]);
+}
+
+
+//------------ InvalidAttributeName ------------------------------------------
+
+/// An attribute name was not valid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidAttributeName(String);
+
+impl InvalidAttributeName {
+    /// Returns the rejected name.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for InvalidAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid attribute name {:?}", self.0)
+    }
+}
+
+impl error::Error for InvalidAttributeName { }
+
+
+//------------ BoolAttr ------------------------------------------------------
+
+/// A boolean HTML element attribute.
+///
+/// The attribute is present if the value is `true` and absent otherwise.
+/// How a present attribute is rendered depends on the target's
+/// [`BoolStyle`].
+pub struct BoolAttr<K> {
+    key: K,
+    value: bool,
+}
+
+impl<K> BoolAttr<K> {
+    /// Creates a new boolean attribute from a key and a value.
+    pub fn new(key: K, value: bool) -> Self {
+        BoolAttr { key, value }
+    }
+}
+
+impl<K: AttributeName> Attributes for BoolAttr<K> {
+    fn render_attrs(self, target: &mut Target) {
+        if !self.value {
+            return
+        }
+        let start = target.len();
+        target.append_slice(b" ");
+        if !render_checked_name(self.key, start, target) {
+            return
+        }
+        let name = start + 1..target.len();
+        let value = match target.bool_style() {
+            BoolStyle::Minimized => None,
+            BoolStyle::Expanded => {
+                let value = target.buf[name.clone()].to_vec();
+                target.append_slice(b"=\"");
+                let value_start = target.len();
+                target.append_slice(&value);
+                let value = value_start..target.len();
+                target.append_slice(b"\"");
+                Some(value)
+            }
+        };
+        target.record_attr(start, name, value);
     }
 }
 
@@ -332,6 +973,53 @@ impl<C: AsRef<str>> Content for Raw<C> {
 }
 
 
+//------------ Category Wrappers ---------------------------------------------
+
+macro_rules! category_wrapper {
+    ( $name:ident: $( $category:ident ),* ) => {
+        pub struct $name<C>(C);
+
+        impl<C> $name<C> {
+            pub(crate) fn new(content: C) -> Self {
+                $name(content)
+            }
+
+            /// Wraps content not covered by the strict builders.
+            ///
+            /// Nothing is checked, so it is up to the caller to only
+            /// use this for content that is allowed where the wrapper
+            /// is accepted.
+            pub fn unchecked(content: C) -> Self {
+                $name(content)
+            }
+        }
+
+        impl<C: Content> Content for $name<C> {
+            fn render_content(self, target: &mut Target) {
+                self.0.render_content(target)
+            }
+        }
+
+        $(
+            impl<C: Content> $category for $name<C> { }
+        )*
+    }
+}
+
+category_wrapper!(FlowContent: Flow);
+category_wrapper!(PhrasingContent: Flow, Phrasing);
+category_wrapper!(ListItem: ListItems);
+category_wrapper!(DescriptionItem: DescriptionItems);
+category_wrapper!(TableRow: TableRows);
+category_wrapper!(TableCell: TableCells);
+category_wrapper!(Summary: );
+category_wrapper!(Legend: );
+category_wrapper!(FigCaption: );
+category_wrapper!(OptionItem: SelectItems, OptionItems);
+category_wrapper!(OptionGroup: SelectItems);
+category_wrapper!(MediaSource: MediaSources);
+
+
 //============ Impl Traits for Tuples ========================================
 
 macro_rules! render_tuple {
@@ -670,3 +1358,316 @@ impl<
     T10: Text, T11: Text, T12: Text,
 > Text for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12) { }
 
+
+
+macro_rules! category_tuple {
+    ( $category:ident ) => {
+        impl $category for () { }
+
+        impl<T: $category> $category for Option<T> { }
+
+        category_tuple!(
+            $category; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12
+        );
+    };
+    ( $category:ident; $head:ident $(, $tail:ident )* ) => {
+        impl<$head: $category, $( $tail: $category ),*> $category
+        for ($head, $( $tail, )*) { }
+
+        category_tuple!($category; $( $tail ),*);
+    };
+    ( $category:ident; ) => { }
+}
+
+category_tuple!(Flow);
+category_tuple!(Phrasing);
+category_tuple!(ListItems);
+category_tuple!(DescriptionItems);
+category_tuple!(TableRows);
+category_tuple!(TableCells);
+category_tuple!(SelectItems);
+category_tuple!(OptionItems);
+category_tuple!(MediaSources);
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(attrs: impl Attributes) -> String {
+        let mut target = Target::new();
+        attrs.render_attrs(&mut target);
+        String::from_utf8(target.as_ref().into()).unwrap()
+    }
+
+    const HOSTILE: &[&str] = &[
+        "x onmouseover=alert(1)",
+        "a\"><script>",
+        "a'b",
+        "a/b",
+        "a>",
+        "a=b",
+        "a\tb",
+        "a\u{0}b",
+        "a\u{85}b",
+        "",
+    ];
+
+    #[test]
+    fn valid_attr_names() {
+        assert!(is_valid_attr_name("class"));
+        assert!(is_valid_attr_name("data-foo"));
+        assert!(is_valid_attr_name("aria-label"));
+        assert!(is_valid_attr_name("@click"));
+        for name in HOSTILE {
+            assert!(!is_valid_attr_name(name), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn hostile_attr() {
+        for name in HOSTILE {
+            assert_eq!(render(Attr::new(*name, "v")), "");
+            assert_eq!(render(Attr::new(String::from(*name), "v")), "");
+            assert_eq!(render(BoolAttr::new(*name, true)), "");
+        }
+        assert_eq!(
+            render((
+                Attr::new("id", "a"),
+                Attr::new("x onmouseover=alert(1)", "v"),
+                Attr::new("title", "b"),
+            )),
+            " id=\"a\" title=\"b\""
+        );
+    }
+
+    #[test]
+    fn hostile_runtime_attrs() {
+        let pairs = vec![
+            (String::from("x onmouseover=alert(1)"), String::from("v")),
+            (String::from("id"), String::from("a")),
+            (String::from("a\"><script>"), String::from("v")),
+        ];
+        assert_eq!(render(pairs.clone()), " id=\"a\"");
+        assert_eq!(
+            render(pairs.iter().cloned().collect::<BTreeMap<_, _>>()),
+            " id=\"a\""
+        );
+        assert_eq!(
+            render(pairs.iter().cloned().collect::<HashMap<_, _>>()),
+            " id=\"a\""
+        );
+        assert_eq!(
+            render(pairs.iter().cloned().collect::<AttrList>()),
+            " id=\"a\""
+        );
+    }
+
+    #[test]
+    fn attr_list_insert() {
+        let mut list = AttrList::new();
+        assert_eq!(
+            list.insert("x onmouseover=alert(1)", "v"),
+            Err(InvalidAttributeName("x onmouseover=alert(1)".into()))
+        );
+        assert!(list.insert("a\"><script>", "v").is_err());
+        assert!(list.is_empty());
+        assert_eq!(list.insert("class", "a"), Ok(()));
+        list.add_class("b a");
+        assert_eq!(list.get("class"), Some("a b"));
+        assert_eq!(render(&list), " class=\"a b\"");
+    }
+
+    #[test]
+    fn expanded_bool_attr() {
+        let mut target = Target::with_bool_style(BoolStyle::Expanded);
+        (
+            BoolAttr::new("hidden", true),
+            BoolAttr::new("a b", true),
+        ).render_attrs(&mut target);
+        assert_eq!(target.as_ref(), b" hidden=\"hidden\"");
+    }
+
+    fn element(attrs: impl Attributes) -> String {
+        let target = EmptyElement::new("br", attrs).render();
+        String::from_utf8(target.as_ref().into()).unwrap()
+    }
+
+    /// Attributes written without `Attr`.
+    struct RawAttrs(&'static str);
+
+    impl Attributes for RawAttrs {
+        fn render_attrs(self, target: &mut Target) {
+            target.append_slice(self.0.as_bytes())
+        }
+    }
+
+    #[test]
+    fn merge_class_and_style() {
+        assert_eq!(
+            element((
+                Attr::new("class", "a b"),
+                Attr::new("id", "x"),
+                Attr::new("CLASS", " b  c "),
+                Attr::new("style", "color: red; "),
+                Attr::new("style", " margin: 0"),
+            )),
+            "<br class=\"a b c\" id=\"x\" style=\"color: red; margin: 0\"/>"
+        );
+        assert_eq!(
+            element((Attr::new("class", ""), Attr::new("class", "a"))),
+            "<br class=\"a\"/>"
+        );
+        assert_eq!(
+            element((Attr::new("style", "  "), Attr::new("style", ""))),
+            "<br style=\"\"/>"
+        );
+    }
+
+    #[test]
+    fn merge_last_wins() {
+        assert_eq!(
+            element((
+                Attr::new("title", "a"),
+                Attr::new("id", "x"),
+                Attr::new("title", "b"),
+                Attr::new("Title", "c"),
+            )),
+            "<br Title=\"c\" id=\"x\"/>"
+        );
+        assert_eq!(
+            element((
+                BoolAttr::new("hidden", true),
+                Attr::new("hidden", "until-found"),
+                BoolAttr::new("hidden", false),
+            )),
+            "<br hidden=\"until-found\"/>"
+        );
+        assert_eq!(
+            element((
+                Attr::new("hidden", "x"), BoolAttr::new("hidden", true)
+            )),
+            "<br hidden/>"
+        );
+    }
+
+    #[test]
+    fn merge_keeps_escaping() {
+        assert_eq!(
+            element((
+                Attr::new("title", "\"<a>\""),
+                Attr::new("class", "a&b"),
+                Attr::new("class", "\"c\""),
+            )),
+            "<br title=\"&quot;&lt;a&gt;&quot;\" \
+             class=\"a&amp;b &quot;c&quot;\"/>"
+        );
+    }
+
+    #[test]
+    fn merge_keeps_raw_attrs() {
+        assert_eq!(
+            element((
+                Attr::new("id", "a"),
+                RawAttrs(" data-x='1' id='b'"),
+                Attr::new("id", "c"),
+            )),
+            "<br id=\"c\" data-x='1' id='b'/>"
+        );
+    }
+
+    #[test]
+    fn merge_nested_elements() {
+        let target = Element::new(
+            "div", (Attr::new("id", "a"), Attr::new("id", "b")),
+            Element::new(
+                "p", (Attr::new("class", "x"), Attr::new("class", "y")), "t"
+            )
+        ).render();
+        assert_eq!(
+            target.as_ref(),
+            b"<div id=\"b\"><p class=\"x y\">t</p></div>"
+        );
+    }
+
+    #[test]
+    fn attr_list_merge() {
+        let mut list = AttrList::new();
+        list.insert("style", "color: red;").unwrap();
+        list.insert("style", "margin: 0").unwrap();
+        list.insert("title", "a").unwrap();
+        list.insert("title", "b").unwrap();
+        assert_eq!(list.get("style"), Some("color: red; margin: 0"));
+        assert_eq!(list.get("title"), Some("b"));
+        assert_eq!(list.len(), 2);
+    }
+
+    fn render_with_policy(content: impl Content) -> String {
+        let mut target = Target::new();
+        target.set_link_policy(
+            LinkPolicy::protect_external("https://example.com/a/").unwrap()
+        );
+        content.render_content(&mut target);
+        String::from_utf8(target.as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn link_policy() {
+        assert_eq!(
+            render_with_policy(Element::new(
+                "a",
+                (
+                    Attr::new("rel", "nofollow noopener"),
+                    Attr::new("href", "https://example.org/?a=1&b=2"),
+                    Attr::new("TARGET", "_blank"),
+                ),
+                "x"
+            )),
+            "<a rel=\"nofollow noopener noreferrer\" \
+             href=\"https://example.org/?a=1&amp;b=2\" TARGET=\"_blank\">\
+             x</a>"
+        );
+        assert_eq!(
+            render_with_policy(EmptyElement::new(
+                "area",
+                (Attr::new("href", "//example.org/"), Attr::new(
+                    "target", "_blank"
+                ))
+            )),
+            "<area href=\"//example.org/\" target=\"_blank\" \
+             rel=\"noopener noreferrer\"/>"
+        );
+        assert_eq!(
+            render_with_policy(Element::new(
+                "form",
+                (Attr::new("action", "https://example.org/"), Attr::new(
+                    "target", "_blank"
+                )),
+                ()
+            )),
+            "<form action=\"https://example.org/\" target=\"_blank\" \
+             rel=\"noopener noreferrer\"></form>"
+        );
+
+        // Internal links, other targets, other elements, and forms
+        // without an action stay as they are.
+        let unchanged = |tag, attrs: &[(&str, &str)]| {
+            let attrs: AttrList = attrs.iter().copied().collect();
+            let plain = Element::new(tag, &attrs, ()).render();
+            assert_eq!(
+                render_with_policy(Element::new(tag, &attrs, ())).as_bytes(),
+                plain.as_ref()
+            );
+        };
+        unchanged("a", &[("href", "b"), ("target", "_blank")]);
+        unchanged("a", &[
+            ("href", "https://example.org/"), ("target", "_self")
+        ]);
+        unchanged("form", &[("target", "_blank")]);
+        unchanged("link", &[
+            ("href", "https://example.org/"), ("target", "_blank")
+        ]);
+    }
+}
@@ -31,6 +31,45 @@ pub fn render_pcdata(s: &str, target: &mut Target) {
     render_escaped(s, target, replace_pcdata_char)
 }
 
+/// Renders the content of an inline script.
+///
+/// Script content is not escaped by HTML parsers, so the only way to
+/// break out of it is a `</script` end tag or a comment opener that
+/// changes how the end tag is found. Both are made harmless by escaping
+/// the character after the `<` with a backslash which is a no-op in
+/// JavaScript strings and regular expressions.
+pub fn render_script(mut s: &str, target: &mut Target) {
+    while let Some(idx) = s.find('<') {
+        let tail = &s.as_bytes()[idx + 1..];
+        let escape = tail.starts_with(b"!--") || (
+            tail.len() >= 7 && tail[..7].eq_ignore_ascii_case(b"/script")
+        );
+        target.append_slice(&s.as_bytes()[..idx + 1]);
+        if escape {
+            target.append_slice(b"\\");
+        }
+        s = &s[idx + 1..];
+    }
+    target.append_slice(s.as_bytes())
+}
+
+/// Renders JSON text embedded in a script element.
+///
+/// The characters `<`, `>`, and `&` can only appear inside JSON strings
+/// where they can be replaced by Unicode escapes. This keeps the data
+/// unchanged while making sure it can neither end the script element nor
+/// be mistaken for markup.
+pub fn render_json(s: &str, target: &mut Target) {
+    render_escaped(s, target, |ch| {
+        match ch {
+            '<' => Some("\\u003c"),
+            '>' => Some("\\u003e"),
+            '&' => Some("\\u0026"),
+            _ => None
+        }
+    })
+}
+
 pub fn format_attr(args: fmt::Arguments, target: &mut Target) {
     WriteEscaped { target, op: replace_attr_char }.write_fmt(args).unwrap()
 }
@@ -40,6 +79,7 @@ pub fn format_pcdata(args: fmt::Arguments, target: &mut Target) {
 }
 
 
+#[allow(clippy::sliced_string_as_bytes)]
 fn render_escaped(
     mut s: &str,
     target: &mut Target,
@@ -83,3 +123,54 @@ where
     }
 }
 
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn escaped(s: &str, op: fn(&str, &mut Target)) -> String {
+        let mut target = Target::new();
+        op(s, &mut target);
+        String::from_utf8(target.as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn script() {
+        assert_eq!(
+            escaped("a = '</SCRIPT>';", render_script),
+            "a = '<\\/SCRIPT>';"
+        );
+        assert_eq!(
+            escaped("a = '</sCrIpT'", render_script),
+            "a = '<\\/sCrIpT'"
+        );
+        assert_eq!(
+            escaped("a = '<!-- b';", render_script),
+            "a = '<\\!-- b';"
+        );
+        assert_eq!(
+            escaped("if (a < b && c <d) { }", render_script),
+            "if (a < b && c <d) { }"
+        );
+        assert_eq!(escaped("a <", render_script), "a <");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            escaped(r#"{"a":"</script>"}"#, render_json),
+            r#"{"a":"\u003c/script\u003e"}"#
+        );
+        assert_eq!(
+            escaped(r#"{"a":"<!--"}"#, render_json),
+            r#"{"a":"\u003c!--"}"#
+        );
+        assert_eq!(
+            escaped(r#"{"a":"b & c"}"#, render_json),
+            r#"{"a":"b \u0026 c"}"#
+        );
+    }
+}
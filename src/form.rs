@@ -0,0 +1,777 @@
+//! HTML forms for Rust types.
+//!
+//! The [`Form`] trait connects a type with an HTML form: it describes the
+//! form’s fields, renders the form, and decodes submitted data back into
+//! the type. It is normally implemented via `#[derive(Form)]` which is
+//! available if the `derive` feature is enabled.
+//!
+//! The derive macro only supports structs with named fields. The type of
+//! each field must implement [`FormField`] which determines the control
+//! used for the field and how its value is parsed. The following field
+//! attributes can be used to modify a field:
+//!
+//! * `#[form(label = "…")]` sets the label of the field, which defaults to
+//!   the field’s name with underscores replaced by spaces,
+//! * `#[form(input = "…")]` uses an `input` element of the given type,
+//! * `#[form(textarea)]` uses a `textarea` element,
+//! * `#[form(options = …)]` uses a `select` element with the given
+//!   options, an expression of type `&'static [(&'static str, &'static
+//!   str)]` with pairs of value and label,
+//! * `#[form(min = …)]` and `#[form(max = …)]` set bounds for fields with
+//!   a type implementing [`NumberField`],
+//! * `#[form(pattern = "…")]` adds a `pattern` attribute for the browser
+//!   to an `input` element, so it can't be combined with `textarea` or
+//!   `options`.
+//!
+//! Fields of an `Option<_>` type and checkboxes are optional, all others
+//! are required. Bounds and the options of a `select` are checked when
+//! decoding. The pattern is not: it only is a hint for the browser, so
+//! a type that needs a certain format has to check it in its
+//! [`FormField::parse_field`].
+//!
+//! If decoding fails, the returned [`Rejected`] value contains the
+//! submitted data and an error message for each invalid field. Rendering
+//! the form with these shows the submitted values again together with
+//! the error messages.
+
+use std::{error, fmt};
+use std::marker::PhantomData;
+use url::form_urlencoded;
+use crate::core::{Content, Flow, Target};
+use crate::html::{self, attr, div, input, label, option, p, select, textarea};
+use crate::html::attr::aria;
+use crate::utils::{display, iter};
+
+#[cfg(feature = "derive")]
+pub use htmlfn_derive::Form;
+
+
+//------------ Form ----------------------------------------------------------
+
+/// A type that can be edited via an HTML form.
+pub trait Form: Sized {
+    /// Returns the fields of the form in the order they are shown.
+    fn fields() -> Vec<Field>;
+
+    /// Creates a value from submitted form data.
+    fn from_form_data(data: &FormData) -> Result<Self, FormErrors>;
+
+    /// Returns the form data representing the value.
+    fn to_form_data(&self) -> FormData;
+
+    /// Decodes an `application/x-www-form-urlencoded` request body.
+    fn decode(body: &[u8]) -> Result<Self, Rejected> {
+        let data = FormData::parse(body);
+        Self::from_form_data(&data).map_err(|errors| {
+            Rejected { data, errors }
+        })
+    }
+
+    /// Returns an empty form submitting to `action`.
+    fn form(action: &str) -> FormView<'_, Self> {
+        FormView::new(action)
+    }
+}
+
+
+//------------ FormField -----------------------------------------------------
+
+/// A type that can be used for a field of a form.
+pub trait FormField: Sized {
+    /// Returns the kind of control used for the field.
+    fn kind() -> FieldKind;
+
+    /// Parses a submitted non-empty value.
+    ///
+    /// If the value is invalid, returns a message for the user.
+    fn parse_field(value: &str) -> Result<Self, String>;
+
+    /// Returns the value to use if no value or an empty value was given.
+    ///
+    /// If this returns `None`, which is the default, the field is
+    /// required.
+    fn missing() -> Option<Self> {
+        None
+    }
+
+    /// Returns the value to submit for the field.
+    fn field_value(&self) -> Option<String>;
+
+    /// Returns the value as a number to check it against bounds.
+    fn field_number(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl FormField for String {
+    fn kind() -> FieldKind {
+        FieldKind::Input("text")
+    }
+
+    fn parse_field(value: &str) -> Result<Self, String> {
+        Ok(value.into())
+    }
+
+    fn field_value(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}
+
+impl FormField for bool {
+    fn kind() -> FieldKind {
+        FieldKind::Checkbox
+    }
+
+    fn parse_field(_value: &str) -> Result<Self, String> {
+        Ok(true)
+    }
+
+    fn missing() -> Option<Self> {
+        Some(false)
+    }
+
+    fn field_value(&self) -> Option<String> {
+        self.then(|| "on".into())
+    }
+}
+
+impl<T: FormField> FormField for Option<T> {
+    fn kind() -> FieldKind {
+        T::kind()
+    }
+
+    fn parse_field(value: &str) -> Result<Self, String> {
+        T::parse_field(value).map(Some)
+    }
+
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+
+    fn field_value(&self) -> Option<String> {
+        self.as_ref().and_then(T::field_value)
+    }
+
+    fn field_number(&self) -> Option<f64> {
+        self.as_ref().and_then(T::field_number)
+    }
+}
+
+//------------ NumberField ---------------------------------------------------
+
+/// A type for a field containing a number.
+///
+/// Only fields of these types can have bounds.
+pub trait NumberField: FormField { }
+
+impl<T: NumberField> NumberField for Option<T> { }
+
+macro_rules! number_field {
+    ( $kind:expr, $message:expr; $( $type:ident ),* ) => {
+        $(
+            impl FormField for $type {
+                fn kind() -> FieldKind {
+                    $kind
+                }
+
+                /// Parses the number.
+                ///
+                /// Infinity and NaN are rejected for floating point
+                /// numbers.
+                fn parse_field(value: &str) -> Result<Self, String> {
+                    let value: Self = value.trim().parse().map_err(|_| {
+                        String::from($message)
+                    })?;
+                    if !value.field_number().is_some_and(f64::is_finite) {
+                        return Err($message.into())
+                    }
+                    Ok(value)
+                }
+
+                fn field_value(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+
+                fn field_number(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+
+            impl NumberField for $type { }
+        )*
+    }
+}
+
+number_field!(
+    FieldKind::Input("number"), "Please enter a whole number.";
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+);
+number_field!(
+    FieldKind::Decimal, "Please enter a number.";
+    f32, f64
+);
+
+
+//------------ FieldKind -----------------------------------------------------
+
+/// The kind of control used for a field.
+#[derive(Clone, Copy, Debug)]
+pub enum FieldKind {
+    /// An `input` element of the given type.
+    Input(&'static str),
+
+    /// A number `input` element that accepts decimal fractions.
+    Decimal,
+
+    /// A checkbox.
+    Checkbox,
+
+    /// A `textarea` element.
+    Textarea,
+
+    /// A `select` element with the given values and their labels.
+    Select(&'static [(&'static str, &'static str)]),
+}
+
+
+//------------ Field ---------------------------------------------------------
+
+/// The description of a field of a form.
+#[derive(Clone, Debug)]
+pub struct Field {
+    name: &'static str,
+    label: &'static str,
+    kind: FieldKind,
+    required: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+    pattern: Option<&'static str>,
+}
+
+impl Field {
+    /// Creates a field for a value of type `T`.
+    pub fn new<T: FormField>(
+        name: &'static str, label: &'static str
+    ) -> Self {
+        Field {
+            name, label,
+            kind: T::kind(),
+            required: T::missing().is_none(),
+            min: None,
+            max: None,
+            pattern: None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Changes the kind of control used for the field.
+    pub fn kind(mut self, kind: FieldKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the `pattern` attribute of the control.
+    ///
+    /// The pattern is only used by the browser. It is not checked when
+    /// parsing the field.
+    pub fn pattern(mut self, pattern: &'static str) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Parses the value of the field from form data.
+    ///
+    /// The value has to be one of the options of a `select` and within the
+    /// bounds of a number. If the value is missing or invalid, adds an
+    /// error message to `errors` and returns `None`.
+    pub fn parse<T: FormField>(
+        &self, data: &FormData, errors: &mut FormErrors
+    ) -> Option<T> {
+        let value = data.get(self.name).filter(|value| {
+            !value.trim().is_empty()
+        });
+        let value = match value {
+            Some(value) if !self.is_option(value) => {
+                Err(String::from("Please select one of the options."))
+            }
+            Some(value) => T::parse_field(value),
+            None => T::missing().ok_or_else(|| {
+                String::from("Please fill in this field.")
+            }),
+        };
+        let value = value.and_then(|value| {
+            match value.field_number() {
+                Some(num) if self.min.is_some_and(|min| num < min) => {
+                    Err(format!(
+                        "Please enter at least {}.",
+                        self.min.unwrap_or_default()
+                    ))
+                }
+                Some(num) if self.max.is_some_and(|max| num > max) => {
+                    Err(format!(
+                        "Please enter at most {}.",
+                        self.max.unwrap_or_default()
+                    ))
+                }
+                _ => Ok(value)
+            }
+        });
+        match value {
+            Ok(value) => Some(value),
+            Err(message) => {
+                errors.insert(self.name, message);
+                None
+            }
+        }
+    }
+
+    /// Returns whether a value is allowed by the options of a `select`.
+    ///
+    /// Fields with other controls allow all values.
+    fn is_option(&self, value: &str) -> bool {
+        match self.kind {
+            FieldKind::Select(options) => {
+                options.iter().any(|(option, _)| *option == value)
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the field with its value and error message for rendering.
+    fn view<'a>(
+        &'a self, data: &'a FormData, errors: &'a FormErrors
+    ) -> FieldView<'a> {
+        FieldView {
+            field: self,
+            value: data.get(self.name),
+            error: errors.get(self.name),
+            error_id: format!("{}-error", self.name),
+        }
+    }
+}
+
+
+//------------ FieldView -----------------------------------------------------
+
+/// A field with its value and error message.
+///
+/// The field is rendered inside a `div` with its label, control, and error
+/// message.
+struct FieldView<'a> {
+    field: &'a Field,
+    value: Option<&'a str>,
+    error: Option<&'a str>,
+    error_id: String,
+}
+
+impl FieldView<'_> {
+    fn render_control(&self, target: &mut Target) {
+        let field = self.field;
+        let value = self.value;
+        let invalid = || self.error.map(|_| {
+            (
+                aria::invalid(aria::Invalid::True),
+                aria::describedby(self.error_id.as_str()),
+            )
+        });
+        let attrs = (
+            attr::id(field.name),
+            attr::name(field.name),
+            attr::required(field.required),
+            invalid(),
+        );
+        let bounds = (
+            field.min.map(|min| attr::min(display(min))),
+            field.max.map(|max| attr::max(display(max))),
+            field.pattern.map(attr::pattern),
+        );
+        match field.kind {
+            FieldKind::Input(input_type) => {
+                input((
+                    attr::type_(input_type), attrs, value.map(attr::value),
+                    bounds,
+                )).render_content(target)
+            }
+            FieldKind::Decimal => {
+                input((
+                    attr::type_("number"), attr::step("any"), attrs,
+                    value.map(attr::value), bounds,
+                )).render_content(target)
+            }
+            FieldKind::Checkbox => {
+                input::checkbox(
+                    field.name, "on", value.is_some(),
+                    (attr::id(field.name), invalid())
+                ).render_content(target)
+            }
+            FieldKind::Textarea => {
+                textarea::attrs(
+                    attrs, value.unwrap_or_default()
+                ).render_content(target)
+            }
+            FieldKind::Select(options) => {
+                select::attrs(attrs, (
+                    (!field.required).then(|| {
+                        option("", value.is_none(), "")
+                    }),
+                    iter(options.iter().map(|&(option_value, text)| {
+                        option(
+                            option_value, value == Some(option_value), text
+                        )
+                    })),
+                )).render_content(target)
+            }
+        }
+    }
+}
+
+impl Content for FieldView<'_> {
+    fn render_content(self, target: &mut Target) {
+        div::class(
+            match self.error {
+                Some(_) => "field field-invalid",
+                None => "field",
+            },
+            (
+                label(self.field.name, self.field.label),
+                Control(&self),
+                self.error.map(|error| {
+                    p::attrs(
+                        (
+                            attr::id(self.error_id.as_str()),
+                            attr::class("field-error"),
+                        ),
+                        error
+                    )
+                }),
+            )
+        ).render_content(target)
+    }
+}
+
+/// The control of a field.
+struct Control<'a>(&'a FieldView<'a>);
+
+impl Content for Control<'_> {
+    fn render_content(self, target: &mut Target) {
+        self.0.render_control(target)
+    }
+}
+
+impl Flow for Control<'_> { }
+
+
+//------------ FormData ------------------------------------------------------
+
+/// The names and values of the fields of a form.
+#[derive(Clone, Debug, Default)]
+pub struct FormData {
+    pairs: Vec<(String, String)>,
+}
+
+impl FormData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an `application/x-www-form-urlencoded` request body.
+    pub fn parse(body: &[u8]) -> Self {
+        FormData {
+            pairs: form_urlencoded::parse(body).into_owned().collect()
+        }
+    }
+
+    /// Returns the first value for the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs.iter().find_map(|(key, value)| {
+            (key == name).then_some(value.as_str())
+        })
+    }
+
+    /// Returns all values for the field with the given name.
+    pub fn get_all<'a>(
+        &'a self, name: &'a str
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.pairs.iter().filter_map(move |(key, value)| {
+            (key == name).then_some(value.as_str())
+        })
+    }
+
+    /// Appends a value for the field with the given name.
+    pub fn append(
+        &mut self, name: impl Into<String>, value: impl Into<String>
+    ) {
+        self.pairs.push((name.into(), value.into()))
+    }
+
+    /// Appends the value of a field if it has one.
+    pub fn append_field<T: FormField>(&mut self, name: &str, value: &T) {
+        if let Some(value) = value.field_value() {
+            self.append(name, value)
+        }
+    }
+
+    /// Returns the data encoded as `application/x-www-form-urlencoded`.
+    pub fn encode(&self) -> String {
+        form_urlencoded::Serializer::new(String::new()).extend_pairs(
+            self.pairs.iter()
+        ).finish()
+    }
+}
+
+
+//------------ FormErrors ----------------------------------------------------
+
+/// Error messages for the fields of a form.
+#[derive(Clone, Debug, Default)]
+pub struct FormErrors {
+    errors: Vec<(String, String)>,
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the error message for the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.errors.iter().find_map(|(key, message)| {
+            (key == name).then_some(message.as_str())
+        })
+    }
+
+    /// Sets the error message for a field.
+    ///
+    /// Only the first message for each field is kept.
+    pub fn insert(
+        &mut self, name: impl Into<String>, message: impl Into<String>
+    ) {
+        let name = name.into();
+        if self.get(&name).is_none() {
+            self.errors.push((name, message.into()))
+        }
+    }
+
+    /// Returns an iterator over field names and error messages.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.errors.iter().map(|(name, message)| {
+            (name.as_str(), message.as_str())
+        })
+    }
+}
+
+
+//------------ Rejected ------------------------------------------------------
+
+/// Submitted form data that could not be decoded.
+#[derive(Clone, Debug)]
+pub struct Rejected {
+    /// The submitted data.
+    pub data: FormData,
+
+    /// The error messages for the invalid fields.
+    pub errors: FormErrors,
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid form data")?;
+        for (name, message) in self.errors.iter() {
+            write!(f, "; {}: {}", name, message)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Rejected { }
+
+
+//------------ FormView ------------------------------------------------------
+
+/// A form for type `F` ready to be rendered.
+///
+/// The form is submitted via POST to its action. Each field is rendered
+/// inside a `div` with the class `field`, or `field field-invalid` if
+/// there is an error message for it. The message follows the control in a
+/// `p` element with the class `field-error`. The ids of the controls are
+/// the names of the fields, so there should only be one form per page.
+pub struct FormView<'a, F> {
+    action: &'a str,
+    data: Option<&'a FormData>,
+    errors: Option<&'a FormErrors>,
+    submit: &'a str,
+    marker: PhantomData<F>,
+}
+
+impl<'a, F> FormView<'a, F> {
+    pub fn new(action: &'a str) -> Self {
+        FormView {
+            action,
+            data: None,
+            errors: None,
+            submit: "Submit",
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the values of the fields.
+    pub fn data(mut self, data: &'a FormData) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Sets the error messages shown with the fields.
+    pub fn errors(mut self, errors: &'a FormErrors) -> Self {
+        self.errors = Some(errors);
+        self
+    }
+
+    /// Sets the data and errors of rejected form data.
+    pub fn rejected(self, rejected: &'a Rejected) -> Self {
+        self.data(&rejected.data).errors(&rejected.errors)
+    }
+
+    /// Sets the label of the submit button, `Submit` by default.
+    pub fn submit(mut self, submit: &'a str) -> Self {
+        self.submit = submit;
+        self
+    }
+}
+
+impl<F: Form> Content for FormView<'_, F> {
+    fn render_content(self, target: &mut Target) {
+        let empty_data = FormData::new();
+        let empty_errors = FormErrors::new();
+        let data = self.data.unwrap_or(&empty_data);
+        let errors = self.errors.unwrap_or(&empty_errors);
+        let fields = F::fields();
+        html::form(
+            (attr::method("post"), attr::action(self.action)),
+            (
+                iter(fields.iter().map(|field| field.view(data, errors))),
+                html::button("submit", (), self.submit),
+            )
+        ).render_content(target)
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn form_data_parse() {
+        let data = FormData::parse(b"a=1&b=x+y%26z&a=2&c=&%C3%A4=%C3%B6");
+        assert_eq!(data.get("a"), Some("1"));
+        assert_eq!(data.get_all("a").collect::<Vec<_>>(), ["1", "2"]);
+        assert_eq!(data.get("b"), Some("x y&z"));
+        assert_eq!(data.get("c"), Some(""));
+        assert_eq!(data.get("ä"), Some("ö"));
+        assert_eq!(data.get("d"), None);
+        assert_eq!(
+            FormData::parse(data.encode().as_bytes()).pairs, data.pairs
+        );
+    }
+
+    /// Parses the value of `field` from `body`.
+    ///
+    /// Returns the value or the error message.
+    fn parse<T: FormField>(field: Field, body: &str) -> Result<T, String> {
+        let mut errors = FormErrors::new();
+        let res = field.parse(&FormData::parse(body.as_bytes()), &mut errors);
+        match res {
+            Some(res) => {
+                assert!(errors.is_empty());
+                Ok(res)
+            }
+            None => Err(errors.get(field.name()).unwrap().into()),
+        }
+    }
+
+    #[test]
+    fn field_parse_required() {
+        let field = || Field::new::<String>("a", "A");
+        assert_eq!(parse::<String>(field(), "a=x"), Ok("x".into()));
+        assert!(parse::<String>(field(), "").is_err());
+        assert!(parse::<String>(field(), "a=+").is_err());
+
+        let field = || Field::new::<Option<String>>("a", "A");
+        assert_eq!(parse::<Option<String>>(field(), "b=x"), Ok(None));
+        assert_eq!(
+            parse::<Option<String>>(field(), "a=x"), Ok(Some("x".into()))
+        );
+
+        let field = || Field::new::<bool>("a", "A");
+        assert_eq!(parse::<bool>(field(), "a=on"), Ok(true));
+        assert_eq!(parse::<bool>(field(), ""), Ok(false));
+    }
+
+    #[test]
+    fn field_parse_numbers() {
+        let field = || Field::new::<i32>("a", "A").min(-1.).max(10.);
+        assert_eq!(parse::<i32>(field(), "a=+7+"), Ok(7));
+        assert_eq!(parse::<i32>(field(), "a=-1"), Ok(-1));
+        assert_eq!(
+            parse::<i32>(field(), "a=-2"),
+            Err("Please enter at least -1.".into())
+        );
+        assert_eq!(
+            parse::<i32>(field(), "a=11"),
+            Err("Please enter at most 10.".into())
+        );
+        assert_eq!(
+            parse::<i32>(field(), "a=1.5"),
+            Err("Please enter a whole number.".into())
+        );
+
+        let field = || Field::new::<f64>("a", "A");
+        assert_eq!(parse::<f64>(field(), "a=1.5"), Ok(1.5));
+        for value in ["NaN", "inf", "-infinity", "1e999"] {
+            assert_eq!(
+                parse::<f64>(field(), &format!("a={}", value)),
+                Err("Please enter a number.".into())
+            );
+        }
+        assert!(
+            parse::<f32>(Field::new::<f32>("a", "A"), "a=1e39").is_err()
+        );
+    }
+
+    #[test]
+    fn field_parse_select() {
+        const OPTIONS: &[(&str, &str)] = &[("r", "Red"), ("g", "Green")];
+        let field = || {
+            Field::new::<Option<String>>("a", "A").kind(
+                FieldKind::Select(OPTIONS)
+            )
+        };
+        assert_eq!(
+            parse::<Option<String>>(field(), "a=g"), Ok(Some("g".into()))
+        );
+        assert_eq!(parse::<Option<String>>(field(), "a="), Ok(None));
+        assert_eq!(
+            parse::<Option<String>>(field(), "a=b"),
+            Err("Please select one of the options.".into())
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::core::{
-    Attributes, AttributeValue, Element, EmptyElement, Content, Text,
-    TextElement, Tokens, raw,
+    Attr, Attributes, AttributeValue, DescriptionItem, Element, EmptyElement,
+    Content, FlowContent, ListItem, PhrasingContent, TableCell, TableRow,
+    Text, TextElement, Tokens, raw,
 };
+use crate::utils::{display, iter};
 
 //------------ doctype -------------------------------------------------------
 
@@ -12,9 +14,27 @@ pub fn doctype() -> impl Content {
 //------------ Elements ------------------------------------------------------
 
 macro_rules! standard {
-    ( $hx:ident ) => {
-        pub fn $hx(content: impl Content) -> impl Content {
-            Element::new(stringify!($hx), (), content)
+    ( $hx:ident $( { $( $extra:item )* } )? ) => {
+        standard!(
+            $hx => impl Content, std::convert::identity, Content
+            { $( $( $extra )* )? }
+        );
+    };
+    (
+        $hx:ident: $category:ident($model:ident)
+        $( { $( $extra:item )* } )?
+    ) => {
+        standard!(
+            $hx => $category<impl Content>, $category::new, $model
+            { $( $( $extra )* )? }
+        );
+    };
+    (
+        $hx:ident => $ret:ty, $wrap:path, $model:ident
+        { $( $extra:item )* }
+    ) => {
+        pub fn $hx(content: impl $model) -> $ret {
+            $wrap(Element::new(stringify!($hx), (), content))
         }
 
         pub mod $hx {
@@ -22,41 +42,47 @@ macro_rules! standard {
 
             pub fn attrs(
                 attrs: impl Attributes,
-                content: impl Content
-            ) -> impl Content {
-                Element::new(stringify!($hx), attrs, content)
+                content: impl $model
+            ) -> $ret {
+                $wrap(Element::new(stringify!($hx), attrs, content))
             }
 
             pub fn class<'a>(
                 class: impl super::Tokens<'a>,
-                content: impl Content
-            ) -> impl Content {
-                Element::new(stringify!($hx), attr::class(class), content)
+                content: impl $model
+            ) -> $ret {
+                $wrap(
+                    Element::new(stringify!($hx), attr::class(class), content)
+                )
             }
 
             pub fn id(
-                id: impl AttributeValue, content: impl Content
-            ) -> impl Content {
-                Element::new(stringify!($hx), attr::id(id), content)
+                id: impl AttributeValue, content: impl $model
+            ) -> $ret {
+                $wrap(Element::new(stringify!($hx), attr::id(id), content))
             }
 
             pub fn id_class<'a>(
                 id: impl AttributeValue,
                 class: impl super::Tokens<'a>,
-                content: impl Content,
-            ) -> impl Content {
-                Element::new(
+                content: impl $model,
+            ) -> $ret {
+                $wrap(Element::new(
                     stringify!($hx),
                     (attr::id(id), attr::class(class)),
                     content
-                )
+                ))
             }
 
             pub fn title(
-                title: impl AttributeValue, content: impl Content
-            ) -> impl Content {
-                Element::new(stringify!($hx), attr::title(title), content)
+                title: impl AttributeValue, content: impl $model
+            ) -> $ret {
+                $wrap(
+                    Element::new(stringify!($hx), attr::title(title), content)
+                )
             }
+
+            $( $extra )*
         }
     }
 }
@@ -84,14 +110,143 @@ pub mod a {
     ) -> impl Content {
         Element::new("a", (attr::class(class), attr::href(href)), content)
     }
+
+    /// Creates a link with the given link types.
+    pub fn rel(
+        href: impl AttributeValue,
+        rel: impl IntoIterator<Item = attr::Rel>,
+        content: impl Content
+    ) -> impl Content {
+        Element::new("a", (attr::href(href), attr::rel(rel)), content)
+    }
+
+    /// Creates a link opening in the given browsing context.
+    ///
+    /// The link policy of the target is applied to the link.
+    pub fn target<'a>(
+        href: impl AttributeValue + 'a,
+        target: attr::LinkTarget<'a>,
+        content: impl Content + 'a
+    ) -> impl Content + 'a {
+        Element::new("a", (attr::href(href), attr::target(target)), content)
+    }
+
+    /// Creates a link opening in a new browsing context.
+    pub fn blank(
+        href: impl AttributeValue, content: impl Content
+    ) -> impl Content {
+        Element::new(
+            "a",
+            (attr::href(href), attr::target(attr::LinkTarget::Blank)),
+            content
+        )
+    }
+}
+
+//--- abbr
+
+standard!(abbr);
+
+//--- address
+
+standard!(address);
+
+//--- area
+
+pub fn area(
+    href: impl AttributeValue,
+    alt: impl AttributeValue,
+    attrs: impl Attributes,
+) -> impl Content {
+    EmptyElement::new("area", (attr::href(href), attr::alt(alt), attrs))
+}
+
+pub mod area {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("area", attrs)
+    }
+}
+
+//--- article
+
+standard!(article);
+
+//--- aside
+
+standard!(aside);
+
+//--- audio
+
+standard!(audio {
+    /// Creates an audio element with controls for a single source.
+    pub fn src(
+        src: impl AttributeValue, fallback: impl Content
+    ) -> impl Content {
+        Element::new(
+            "audio", (attr::src(src), attr::controls(true)), fallback
+        )
+    }
+});
+
+//--- b
+
+standard!(b);
+
+//--- base
+
+/// Creates the base element setting the URL relative URLs resolve against.
+pub fn base(href: impl AttributeValue) -> impl Content {
+    EmptyElement::new("base", attr::href(href))
+}
+
+pub mod base {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("base", attrs)
+    }
 }
 
+//--- blockquote
+
+standard!(blockquote {
+    pub fn cite<'a>(
+        cite: &'a url::Url, content: impl Content + 'a
+    ) -> impl Content + 'a {
+        Element::new("blockquote", attr::cite(cite), content)
+    }
+});
+
 //--- body
 
 pub fn body(content: impl Content) -> impl Content {
     Element::new("body", (), content)
 }
 
+//--- br
+
+pub fn br() -> impl Content {
+    EmptyElement::new("br", ())
+}
+
+pub mod br {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("br", attrs)
+    }
+
+    pub fn class<'a>(class: impl super::Tokens<'a>) -> impl Content {
+        EmptyElement::new("br", attr::class(class))
+    }
+
+    pub fn id(id: impl AttributeValue) -> impl Content {
+        EmptyElement::new("br", attr::id(id))
+    }
+}
+
 //--- button
 
 pub fn button(
@@ -102,23 +257,155 @@ pub fn button(
     Element::new("button", (attr::type_(button_type), attrs), content)
 }
 
+//--- canvas
+
+standard!(canvas {
+    pub fn size(
+        width: u64, height: u64, fallback: impl Content
+    ) -> impl Content {
+        Element::new(
+            "canvas", (attr::width(width), attr::height(height)), fallback
+        )
+    }
+});
+
+//--- cite
+
+standard!(cite);
+
+//--- code
+
+standard!(code);
+
+//--- datalist
+
+pub fn datalist(
+    id: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("datalist", attr::id(id), content)
+}
+
+//--- del, ins
+
+standard!(del {
+    pub fn cite<'a>(
+        cite: &'a url::Url, content: impl Content + 'a
+    ) -> impl Content + 'a {
+        Element::new("del", attr::cite(cite), content)
+    }
+
+    pub fn datetime(
+        datetime: attr::Datetime, content: impl Content
+    ) -> impl Content {
+        Element::new("del", attr::datetime(datetime), content)
+    }
+});
+
+standard!(ins {
+    pub fn cite<'a>(
+        cite: &'a url::Url, content: impl Content + 'a
+    ) -> impl Content + 'a {
+        Element::new("ins", attr::cite(cite), content)
+    }
+
+    pub fn datetime(
+        datetime: attr::Datetime, content: impl Content
+    ) -> impl Content {
+        Element::new("ins", attr::datetime(datetime), content)
+    }
+});
+
+//--- details, summary
+
+standard!(details {
+    /// Creates a details element that is initially expanded.
+    pub fn open(content: impl Content) -> impl Content {
+        Element::new("details", attr::open(true), content)
+    }
+
+    /// Creates a details element that is part of an exclusive group.
+    ///
+    /// Only one of the details elements with the same name can be open at
+    /// any time.
+    pub fn name(
+        name: impl AttributeValue, content: impl Content
+    ) -> impl Content {
+        Element::new("details", attr::name(name), content)
+    }
+});
+
+standard!(summary);
+
+//--- dfn
+
+standard!(dfn);
+
+//--- dialog
+
+standard!(dialog {
+    /// Creates a dialog element that is initially shown.
+    pub fn open(content: impl Content) -> impl Content {
+        Element::new("dialog", attr::open(true), content)
+    }
+});
+
 //--- div
 
 standard!(div);
 
 //--- dl, dd, dt
 
-standard!(dl);
+standard!(dl {
+    /// Creates a list with a term and description for each pair.
+    pub fn pairs<I, T, D>(pairs: I) -> impl Content
+    where I: IntoIterator<Item = (T, D)>, T: Content, D: Content {
+        super::dl(iter(pairs.into_iter().map(|(term, desc)| {
+            (dt(term), dd(desc))
+        })))
+    }
+});
 standard!(dd);
 standard!(dt);
 
+//--- em
+
+standard!(em);
+
+//--- embed
+
+pub fn embed(
+    src: impl AttributeValue, mime_type: impl AttributeValue
+) -> impl Content {
+    EmptyElement::new("embed", (attr::src(src), attr::type_(mime_type)))
+}
+
+pub mod embed {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("embed", attrs)
+    }
+}
+
+//--- fieldset, legend
+
+standard!(fieldset);
+standard!(legend);
+
+//--- figure, figcaption
+
+standard!(figure);
+standard!(figcaption);
+
 //--- footer
 
 standard!(footer);
 
 //--- form
 
-pub fn form(attrs: impl Attributes, content: impl Content) -> impl Content {
+pub fn form(
+    attrs: impl Attributes, content: impl Content
+) -> impl Content {
     Element::new("form", attrs, content)
 }
 
@@ -141,6 +428,24 @@ pub fn head(content: impl Content) -> impl Content {
 
 standard!(header);
 
+//--- hgroup
+
+standard!(hgroup);
+
+//--- hr
+
+pub fn hr() -> impl Content {
+    EmptyElement::new("hr", ())
+}
+
+pub mod hr {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("hr", attrs)
+    }
+}
+
 //--- html
 
 pub fn html(
@@ -151,6 +456,26 @@ pub fn html(
     Element::new("html", attr::lang(lang), (head, body))
 }
 
+//--- i
+
+standard!(i);
+
+//--- iframe
+
+pub fn iframe(
+    src: impl AttributeValue, title: impl AttributeValue
+) -> impl Content {
+    Element::new("iframe", (attr::src(src), attr::title(title)), ())
+}
+
+pub mod iframe {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        Element::new("iframe", attrs, ())
+    }
+}
+
 //--- img
 
 pub fn img(
@@ -173,6 +498,121 @@ pub fn input(attrs: impl Attributes) -> impl Content {
     EmptyElement::new("input", attrs)
 }
 
+/// Inputs of a specific type.
+///
+/// The type of the input is fixed by the function. A `type` attribute
+/// given via `attrs` is ignored.
+pub mod input {
+    use super::*;
+
+    pub fn checkbox(
+        name: impl AttributeValue,
+        value: impl AttributeValue,
+        checked: bool,
+        attrs: impl Attributes,
+    ) -> impl Content {
+        typed(
+            "checkbox", name,
+            (
+                attr::value(value),
+                attr::checked(checked),
+                attrs
+            )
+        )
+    }
+
+    pub fn date(
+        name: impl AttributeValue, attrs: impl Attributes
+    ) -> impl Content {
+        typed("date", name, attrs)
+    }
+
+    pub fn email(
+        name: impl AttributeValue, attrs: impl Attributes
+    ) -> impl Content {
+        typed("email", name, attrs)
+    }
+
+    pub fn file(
+        name: impl AttributeValue,
+        accept: impl AttributeValue,
+        attrs: impl Attributes,
+    ) -> impl Content {
+        typed("file", name, (attr::accept(accept), attrs))
+    }
+
+    pub fn hidden(
+        name: impl AttributeValue, value: impl AttributeValue
+    ) -> impl Content {
+        typed("hidden", name, attr::value(value))
+    }
+
+    /// Creates a number input.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if any of the numbers is NaN or infinite.
+    pub fn number(
+        name: impl AttributeValue,
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+        attrs: impl Attributes,
+    ) -> impl Content {
+        typed(
+            "number", name,
+            (
+                min.map(|min| attr::min(super::finite(min))),
+                max.map(|max| attr::max(super::finite(max))),
+                step.map(|step| attr::step(super::finite(step))),
+                attrs
+            )
+        )
+    }
+
+    pub fn text(
+        name: impl AttributeValue, attrs: impl Attributes
+    ) -> impl Content {
+        typed("text", name, attrs)
+    }
+
+    fn typed(
+        input_type: &'static str,
+        name: impl AttributeValue,
+        attrs: impl Attributes,
+    ) -> impl Content {
+        // The type comes last so that it replaces any type in `attrs` when
+        // the element merges duplicate attributes.
+        EmptyElement::new(
+            "input",
+            (attr::name(name), attrs, attr::type_(input_type))
+        )
+    }
+}
+
+//--- kbd
+
+standard!(kbd);
+
+//--- label
+
+pub fn label(
+    for_: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("label", attr::for_(for_), content)
+}
+
+pub mod label {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes,
+        content: impl Content
+    ) -> impl Content {
+        Element::new("label", attrs, content)
+    }
+}
+
 //--- li
 
 standard!(li);
@@ -186,6 +626,82 @@ pub mod link {
         EmptyElement::new("link", attrs)
     }
 
+    /// Links to a version of the page in another language or format.
+    pub fn alternate(
+        href: impl AttributeValue, hreflang: impl AttributeValue
+    ) -> impl Content {
+        link((
+            Attr::new("rel", "alternate"),
+            Attr::new("hreflang", hreflang),
+            Attr::new("href", href),
+        ))
+    }
+
+    /// Links to the preferred URL of the page.
+    pub fn canonical(href: impl AttributeValue) -> impl Content {
+        link((
+            Attr::new("rel", "canonical"),
+            Attr::new("href", href),
+        ))
+    }
+
+    pub fn icon(href: impl AttributeValue) -> impl Content {
+        link((
+            Attr::new("rel", "icon"),
+            Attr::new("href", href),
+        ))
+    }
+
+    /// Links a microdata property to a URL.
+    pub fn itemprop(
+        name: impl AttributeValue, href: impl AttributeValue
+    ) -> impl Content {
+        link((
+            Attr::new("itemprop", name),
+            Attr::new("href", href),
+        ))
+    }
+
+    pub fn manifest(href: impl AttributeValue) -> impl Content {
+        link((
+            Attr::new("rel", "manifest"),
+            Attr::new("href", href),
+        ))
+    }
+
+    pub fn modulepreload(href: impl AttributeValue) -> impl Content {
+        link((
+            Attr::new("rel", "modulepreload"),
+            Attr::new("href", href),
+        ))
+    }
+
+    /// Asks the browser to open a connection to an origin early.
+    pub fn preconnect(href: impl AttributeValue) -> impl Content {
+        link((
+            Attr::new("rel", "preconnect"),
+            Attr::new("href", href),
+        ))
+    }
+
+    /// Asks the browser to fetch a resource needed by the page early.
+    ///
+    /// Fonts are always fetched in CORS mode, so preloading them also
+    /// adds the `crossorigin` attribute needed for the preloaded
+    /// response to be used.
+    pub fn preload(
+        href: impl AttributeValue, as_: super::attr::Destination
+    ) -> impl Content {
+        link((
+            Attr::new("rel", "preload"),
+            Attr::new("href", href),
+            super::attr::as_(as_),
+            (as_ == super::attr::Destination::Font).then(|| {
+                super::attr::crossorigin(super::attr::CrossOrigin::Anonymous)
+            }),
+        ))
+    }
+
     pub fn stylesheet(href: impl AttributeValue) -> impl Content {
         link((
             Attr::new("rel", "stylesheet"),
@@ -198,6 +714,28 @@ pub mod link {
 
 standard!(main);
 
+//--- map
+
+pub fn map(
+    name: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("map", attr::name(name), content)
+}
+
+//--- mark
+
+standard!(mark);
+
+//--- menu
+
+standard!(menu {
+    /// Creates a menu with an item for each element of an iterator.
+    pub fn items<I, F, C>(items: I, mut op: F) -> impl Content
+    where I: IntoIterator, F: FnMut(I::Item) -> C, C: Content {
+        super::menu(iter(items.into_iter().map(move |item| li(op(item)))))
+    }
+});
+
 //--- meta
 
 pub mod meta {
@@ -210,7 +748,7 @@ pub mod meta {
     }
 
     pub fn name(
-        name: &'static str, content: impl AttributeValue
+        name: impl AttributeValue, content: impl AttributeValue
     ) -> impl Content {
         EmptyElement::new("meta",
             (
@@ -220,134 +758,3540 @@ pub mod meta {
         )
     }
 
-    pub fn utf8() -> impl Content {
-        charset("utf-8")
-    }
-
-    pub fn viewport(content: impl AttributeValue) -> impl Content {
-        name("viewport", content)
+    /// Creates a pragma directive.
+    pub fn http_equiv(
+        name: impl AttributeValue, content: impl AttributeValue
+    ) -> impl Content {
+        EmptyElement::new("meta",
+            (
+                Attr::new("http-equiv", name),
+                Attr::new("content", content)
+            )
+        )
+    }
+
+    /// Creates a microdata property with a value not shown on the page.
+    pub fn itemprop(
+        name: impl AttributeValue, content: impl AttributeValue
+    ) -> impl Content {
+        EmptyElement::new("meta",
+            (
+                Attr::new("itemprop", name),
+                Attr::new("content", content)
+            )
+        )
+    }
+
+    /// Creates a property such as the ones defined by OpenGraph.
+    pub fn property(
+        property: impl AttributeValue, content: impl AttributeValue
+    ) -> impl Content {
+        EmptyElement::new("meta",
+            (
+                Attr::new("property", property),
+                Attr::new("content", content)
+            )
+        )
+    }
+
+    pub fn utf8() -> impl Content {
+        charset("utf-8")
+    }
+
+    pub fn viewport(content: impl AttributeValue) -> impl Content {
+        name("viewport", content)
+    }
+}
+
+//--- meter
+
+/// Creates a meter element.
+///
+/// # Panics
+///
+/// The function panics if any of the numbers is NaN or infinite.
+pub fn meter(
+    value: f64, min: f64, max: f64, content: impl Content
+) -> impl Content {
+    Element::new(
+        "meter",
+        (
+            attr::value(finite(value)),
+            attr::min(finite(min)),
+            attr::max(finite(max)),
+        ),
+        content
+    )
+}
+
+pub mod meter {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes,
+        content: impl Content
+    ) -> impl Content {
+        Element::new("meter", attrs, content)
+    }
+}
+
+//--- nav
+
+standard!(nav);
+
+//--- noscript
+
+standard!(noscript);
+
+//--- object
+
+pub fn object(
+    data: impl AttributeValue,
+    mime_type: impl AttributeValue,
+    fallback: impl Content
+) -> impl Content {
+    Element::new(
+        "object",
+        (Attr::new("data", data), attr::type_(mime_type)),
+        fallback
+    )
+}
+
+pub mod object {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes, fallback: impl Content
+    ) -> impl Content {
+        Element::new("object", attrs, fallback)
+    }
+}
+
+//--- ol
+
+standard!(ol {
+    /// Creates a list whose first item has the given ordinal value.
+    pub fn start(
+        start: i64, content: impl Content
+    ) -> impl Content {
+        Element::new("ol", attr::start(start), content)
+    }
+
+    /// Creates a list with an item for each element of an iterator.
+    pub fn items<I, F, C>(items: I, mut op: F) -> impl Content
+    where I: IntoIterator, F: FnMut(I::Item) -> C, C: Content {
+        super::ol(iter(items.into_iter().map(move |item| li(op(item)))))
+    }
+});
+
+//--- optgroup, option
+
+pub fn optgroup(
+    label: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("optgroup", attr::label(label), content)
+}
+
+pub fn option(
+    value: impl AttributeValue, selected: bool, label: impl Text
+) -> impl Content {
+    TextElement::new(
+        "option",
+        (
+            attr::value(value),
+            attr::selected(selected),
+        ),
+        label
+    )
+}
+
+pub mod option {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes, label: impl Text) -> impl Content {
+        TextElement::new("option", attrs, label)
+    }
+}
+
+//--- output
+
+pub fn output(
+    name: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("output", attr::name(name), content)
+}
+
+pub mod output {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes,
+        content: impl Content
+    ) -> impl Content {
+        Element::new("output", attrs, content)
+    }
+}
+
+//--- p
+
+standard!(p);
+
+//--- picture
+
+standard!(picture);
+
+//--- pre
+
+standard!(pre);
+
+//--- progress
+
+/// Creates a progress element.
+///
+/// # Panics
+///
+/// The function panics if any of the numbers is NaN or infinite.
+pub fn progress(
+    value: f64, max: f64, content: impl Content
+) -> impl Content {
+    Element::new(
+        "progress",
+        (attr::value(finite(value)), attr::max(finite(max))),
+        content
+    )
+}
+
+pub mod progress {
+    use super::*;
+
+    pub fn indeterminate(
+        content: impl Content
+    ) -> impl Content {
+        Element::new("progress", (), content)
+    }
+}
+
+//--- q
+
+standard!(q {
+    pub fn cite<'a>(
+        cite: &'a url::Url, content: impl Content + 'a
+    ) -> impl Content + 'a {
+        Element::new("q", attr::cite(cite), content)
+    }
+});
+
+//--- s
+
+standard!(s);
+
+//--- samp
+
+standard!(samp);
+
+//--- script
+
+/// Creates a classic script loaded from a URL.
+pub fn script(src: impl AttributeValue) -> impl Content {
+    Element::new("script", attr::src(src), ())
+}
+
+pub mod script {
+    use super::*;
+    use crate::core::Target;
+    use crate::escape;
+
+    /// Creates an external script with the given attributes.
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        Element::new("script", attrs, ())
+    }
+
+    /// Creates a script loaded from a URL as a JavaScript module.
+    pub fn module(
+        src: impl AttributeValue
+    ) -> impl Content {
+        Element::new(
+            "script", (attr::type_("module"), attr::src(src)), ()
+        )
+    }
+
+    /// Creates an inline script.
+    ///
+    /// Character sequences that would end the script element early are
+    /// escaped.
+    pub fn inline(
+        code: impl AsRef<str>
+    ) -> impl Content {
+        Element::new("script", (), Code(code))
+    }
+
+    /// Creates an inline script with the given attributes.
+    pub fn inline_attrs(
+        attrs: impl Attributes, code: impl AsRef<str>
+    ) -> impl Content {
+        Element::new("script", attrs, Code(code))
+    }
+
+    struct Code<S>(S);
+
+    impl<S: AsRef<str>> Content for Code<S> {
+        fn render_content(self, target: &mut Target) {
+            escape::render_script(self.0.as_ref(), target)
+        }
+    }
+}
+
+//--- section
+
+standard!(section);
+
+//--- select
+
+pub fn select(
+    name: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("select", attr::name(name), content)
+}
+
+pub mod select {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes,
+        content: impl Content
+    ) -> impl Content {
+        Element::new("select", attrs, content)
+    }
+}
+
+//--- slot
+
+standard!(slot {
+    /// Creates a named slot with fallback content.
+    pub fn named(
+        name: impl AttributeValue, fallback: impl Content
+    ) -> impl Content {
+        Element::new("slot", attr::name(name), fallback)
+    }
+});
+
+//--- small
+
+standard!(small);
+
+//--- source
+
+pub fn source(attrs: impl Attributes) -> impl Content {
+    EmptyElement::new("source", attrs)
+}
+
+pub mod source {
+    use super::*;
+
+    /// Creates a media resource for a `video` or `audio` element.
+    pub fn src(
+        src: impl AttributeValue, mime_type: impl AttributeValue
+    ) -> impl Content {
+        source((attr::src(src), attr::type_(mime_type)))
+    }
+
+    /// Creates an image source for a `picture` element.
+    pub fn srcset(
+        srcset: impl Attributes, media: impl AttributeValue
+    ) -> impl Content {
+        source((srcset, attr::media(media)))
+    }
+}
+
+//--- span
+
+standard!(span);
+
+//--- strong
+
+standard!(strong);
+
+//--- sub, sup
+
+standard!(sub);
+standard!(sup);
+
+//--- table
+
+standard!(table);
+
+//--- td
+
+standard!(td);
+
+//--- template
+
+standard!(template {
+    /// Creates a template that attaches a declarative shadow root.
+    ///
+    /// When parsed, the content of the template becomes the shadow tree
+    /// of the template’s parent element.
+    pub fn shadow_root(
+        mode: attr::ShadowRootMode, content: impl Content
+    ) -> impl Content {
+        Element::new("template", attr::shadowrootmode(mode), content)
+    }
+});
+
+//--- textarea
+
+pub fn textarea(
+    name: impl AttributeValue, content: impl Text
+) -> impl Content {
+    TextElement::new("textarea", attr::name(name), content)
+}
+
+pub mod textarea {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes, content: impl Text
+    ) -> impl Content {
+        TextElement::new("textarea", attrs, content)
+    }
+}
+
+//--- th
+
+standard!(th);
+
+//--- time
+
+standard!(time {
+    pub fn datetime(
+        datetime: attr::Datetime, content: impl Content
+    ) -> impl Content {
+        Element::new("time", attr::datetime(datetime), content)
+    }
+});
+
+//--- title
+
+pub fn title(title: impl Text) -> impl Content {
+    TextElement::new("title", (), title)
+}
+
+//--- tr
+
+standard!(tr);
+
+//--- track
+
+pub fn track(
+    kind: attr::TrackKind,
+    src: impl AttributeValue,
+    srclang: impl AttributeValue,
+    label: impl AttributeValue,
+) -> impl Content {
+    EmptyElement::new(
+        "track",
+        (
+            attr::kind(kind), attr::src(src), attr::srclang(srclang),
+            attr::label(label),
+        )
+    )
+}
+
+pub mod track {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("track", attrs)
+    }
+}
+
+//--- tt
+
+pub fn tt(
+    content: impl Content
+) -> impl Content {
+    Element::new("tt", (), content)
+}
+
+//--- u
+
+standard!(u);
+
+//--- ul
+
+standard!(ul {
+    /// Creates a list with an item for each element of an iterator.
+    pub fn items<I, F, C>(items: I, mut op: F) -> impl Content
+    where I: IntoIterator, F: FnMut(I::Item) -> C, C: Content {
+        super::ul(iter(items.into_iter().map(move |item| li(op(item)))))
+    }
+});
+
+//--- var
+
+standard!(var);
+
+//--- video
+
+standard!(video {
+    /// Creates a video element with controls for a single source.
+    pub fn src(
+        src: impl AttributeValue, fallback: impl Content
+    ) -> impl Content {
+        Element::new(
+            "video", (attr::src(src), attr::controls(true)), fallback
+        )
+    }
+});
+
+//--- wbr
+
+pub fn wbr() -> impl Content {
+    EmptyElement::new("wbr", ())
+}
+
+pub mod wbr {
+    use super::*;
+
+    pub fn attrs(attrs: impl Attributes) -> impl Content {
+        EmptyElement::new("wbr", attrs)
+    }
+
+    pub fn class<'a>(class: impl super::Tokens<'a>) -> impl Content {
+        EmptyElement::new("wbr", attr::class(class))
+    }
+
+    pub fn id(id: impl AttributeValue) -> impl Content {
+        EmptyElement::new("wbr", attr::id(id))
+    }
+}
+
+//--- Helpers
+
+/// Returns a number for use in an attribute, checking that it is finite.
+///
+/// Attributes such as `value` or `min` only accept finite numbers, so
+/// rendering NaN or infinity would produce invalid markup.
+fn finite(value: f64) -> impl AttributeValue {
+    assert!(value.is_finite(), "non-finite number {}", value);
+    display(value)
+}
+
+
+//------------ Strict Elements -----------------------------------------------
+
+/// Elements that only accept content of the permitted categories.
+///
+/// The functions in this module mirror those of the same name in the
+/// parent module but require their content to implement the content
+/// category traits defined in [`core`][crate::core]. Invalid nesting, such
+/// as a `div` inside a `p` or a `p` directly inside a `ul`, is refused at
+/// compile time.
+///
+/// Only the functions in this module produce content of a category. The
+/// elements of the parent module are plain content and thus can't be used
+/// as the content of a strict element, so that the check extends all the
+/// way down. Content the module doesn't cover, such as a custom element,
+/// can be brought in through the `unchecked` function of the wrapper types
+/// in [`core`][crate::core].
+pub mod strict {
+    use super::*;
+    use crate::core::{
+        DescriptionItems, FigCaption, Flow, Legend, ListItems, MediaSource,
+        MediaSources, OptionGroup, OptionItem, OptionItems, Phrasing,
+        SelectItems, Summary, TableCells, TableRows,
+    };
+
+    //--- a
+
+    pub fn a(
+        href: impl AttributeValue, content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::a(href, content))
+    }
+
+    pub mod a {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes, content: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::a::attrs(attrs, content))
+        }
+
+        /// Creates a link opening in the given browsing context.
+        pub fn target<'a>(
+            href: impl AttributeValue + 'a,
+            target: attr::LinkTarget<'a>,
+            content: impl Phrasing + 'a
+        ) -> PhrasingContent<impl Content + 'a> {
+            PhrasingContent::new(
+                crate::html::a::target(href, target, content)
+            )
+        }
+
+        /// Creates a link opening in a new browsing context.
+        pub fn blank(
+            href: impl AttributeValue, content: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::a::blank(href, content))
+        }
+    }
+
+    //--- abbr
+
+    standard!(abbr: PhrasingContent(Phrasing));
+
+    //--- address
+
+    standard!(address: FlowContent(Flow));
+
+    //--- area
+
+    pub fn area(
+        href: impl AttributeValue,
+        alt: impl AttributeValue,
+        attrs: impl Attributes,
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::area(href, alt, attrs))
+    }
+
+    //--- article
+
+    standard!(article: FlowContent(Flow));
+
+    //--- aside
+
+    standard!(aside: FlowContent(Flow));
+
+    //--- audio
+
+    /// Creates an audio element.
+    ///
+    /// The fallback is shown by browsers that don’t support the element.
+    pub fn audio(
+        sources: impl MediaSources, fallback: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::audio((sources, fallback)))
+    }
+
+    pub mod audio {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes,
+            sources: impl MediaSources,
+            fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::audio::attrs(attrs, (sources, fallback))
+            )
+        }
+
+        /// Creates an audio element with controls for a single source.
+        pub fn src(
+            src: impl AttributeValue, fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::audio::src(src, fallback))
+        }
+    }
+
+    //--- b
+
+    standard!(b: PhrasingContent(Phrasing));
+
+    //--- blockquote
+
+    standard!(blockquote: FlowContent(Flow) {
+        pub fn cite<'a>(
+            cite: &'a url::Url, content: impl Flow + 'a
+        ) -> FlowContent<impl Content + 'a> {
+            FlowContent::new(crate::html::blockquote::cite(cite, content))
+        }
+    });
+
+    //--- br
+
+    pub fn br() -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::br())
+    }
+
+    pub mod br {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::br::attrs(attrs))
+        }
+
+        pub fn class<'a>(
+            class: impl Tokens<'a>
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::br::class(class))
+        }
+
+        pub fn id(id: impl AttributeValue) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::br::id(id))
+        }
+    }
+
+    //--- button
+
+    pub fn button(
+        button_type: impl AttributeValue,
+        attrs: impl Attributes,
+        content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::button(button_type, attrs, content))
+    }
+
+    //--- canvas
+
+    standard!(canvas: PhrasingContent(Phrasing) {
+        pub fn size(
+            width: u64, height: u64, fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::canvas::size(width, height, fallback)
+            )
+        }
+    });
+
+    //--- cite
+
+    standard!(cite: PhrasingContent(Phrasing));
+
+    //--- code
+
+    standard!(code: PhrasingContent(Phrasing));
+
+    //--- datalist
+
+    pub fn datalist(
+        id: impl AttributeValue, content: impl OptionItems
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::datalist(id, content))
+    }
+
+    //--- del, ins
+
+    standard!(del: PhrasingContent(Phrasing) {
+        pub fn cite<'a>(
+            cite: &'a url::Url, content: impl Phrasing + 'a
+        ) -> PhrasingContent<impl Content + 'a> {
+            PhrasingContent::new(crate::html::del::cite(cite, content))
+        }
+
+        pub fn datetime(
+            datetime: attr::Datetime, content: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::del::datetime(datetime, content)
+            )
+        }
+    });
+    standard!(ins: PhrasingContent(Phrasing) {
+        pub fn cite<'a>(
+            cite: &'a url::Url, content: impl Phrasing + 'a
+        ) -> PhrasingContent<impl Content + 'a> {
+            PhrasingContent::new(crate::html::ins::cite(cite, content))
+        }
+
+        pub fn datetime(
+            datetime: attr::Datetime, content: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::ins::datetime(datetime, content)
+            )
+        }
+    });
+
+    //--- details
+
+    /// Creates a details element with its summary.
+    pub fn details(
+        summary: Summary<impl Content>, content: impl Flow
+    ) -> FlowContent<impl Content> {
+        FlowContent::new(super::details((summary, content)))
+    }
+
+    pub mod details {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes,
+            summary: Summary<impl Content>,
+            content: impl Flow
+        ) -> FlowContent<impl Content> {
+            FlowContent::new(
+                crate::html::details::attrs(attrs, (summary, content))
+            )
+        }
+
+        /// Creates a details element that is initially expanded.
+        pub fn open(
+            summary: Summary<impl Content>, content: impl Flow
+        ) -> FlowContent<impl Content> {
+            FlowContent::new(
+                crate::html::details::open((summary, content))
+            )
+        }
+    }
+
+    standard!(summary: Summary(Phrasing));
+
+    //--- dfn
+
+    standard!(dfn: PhrasingContent(Phrasing));
+
+    //--- dialog
+
+    standard!(dialog: FlowContent(Flow));
+
+    //--- div
+
+    standard!(div: FlowContent(Flow));
+
+    //--- dl, dd, dt
+
+    standard!(dl: FlowContent(DescriptionItems));
+    standard!(dd: DescriptionItem(Flow));
+    standard!(dt: DescriptionItem(Flow));
+
+    //--- em
+
+    standard!(em: PhrasingContent(Phrasing));
+
+    //--- embed
+
+    pub fn embed(
+        src: impl AttributeValue, mime_type: impl AttributeValue
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::embed(src, mime_type))
+    }
+
+    //--- fieldset
+
+    standard!(fieldset: FlowContent(Flow) {
+        /// Creates a fieldset with a legend.
+        pub fn legend(
+            legend: Legend<impl Content>, content: impl Flow
+        ) -> FlowContent<impl Content> {
+            FlowContent::new(crate::html::fieldset((legend, content)))
+        }
+    });
+    standard!(legend: Legend(Phrasing));
+
+    //--- figure
+
+    standard!(figure: FlowContent(Flow) {
+        /// Creates a figure with a caption before its content.
+        pub fn caption(
+            caption: FigCaption<impl Content>, content: impl Flow
+        ) -> FlowContent<impl Content> {
+            FlowContent::new(crate::html::figure((caption, content)))
+        }
+
+        /// Creates a figure with a caption after its content.
+        pub fn caption_last(
+            content: impl Flow, caption: FigCaption<impl Content>
+        ) -> FlowContent<impl Content> {
+            FlowContent::new(crate::html::figure((content, caption)))
+        }
+    });
+    standard!(figcaption: FigCaption(Flow));
+
+    //--- footer
+
+    standard!(footer: FlowContent(Flow));
+
+    //--- form
+
+    pub fn form(
+        attrs: impl Attributes, content: impl Flow
+    ) -> FlowContent<impl Content> {
+        FlowContent::new(super::form(attrs, content))
+    }
+
+    //--- h1
+
+    standard!(h1: FlowContent(Phrasing));
+    standard!(h2: FlowContent(Phrasing));
+    standard!(h3: FlowContent(Phrasing));
+    standard!(h4: FlowContent(Phrasing));
+    standard!(h5: FlowContent(Phrasing));
+    standard!(h6: FlowContent(Phrasing));
+
+    //--- header
+
+    standard!(header: FlowContent(Flow));
+
+    //--- hgroup
+
+    standard!(hgroup: FlowContent(Flow));
+
+    //--- hr
+
+    pub fn hr() -> FlowContent<impl Content> {
+        FlowContent::new(super::hr())
+    }
+
+    pub mod hr {
+        use super::*;
+
+        pub fn attrs(attrs: impl Attributes) -> FlowContent<impl Content> {
+            FlowContent::new(crate::html::hr::attrs(attrs))
+        }
+    }
+
+    //--- i
+
+    standard!(i: PhrasingContent(Phrasing));
+
+    //--- iframe
+
+    pub fn iframe(
+        src: impl AttributeValue, title: impl AttributeValue
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::iframe(src, title))
+    }
+
+    //--- img
+
+    pub fn img(
+        src: impl AttributeValue, alt: impl AttributeValue
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::img(src, alt))
+    }
+
+    pub mod img {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::img::attrs(attrs))
+        }
+    }
+
+    //--- input
+
+    pub fn input(attrs: impl Attributes) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::input(attrs))
+    }
+
+    /// Inputs of a specific type.
+    ///
+    /// See [`html::input`][crate::html::input] for the details.
+    pub mod input {
+        use super::*;
+        use crate::html::input as base;
+
+        pub fn checkbox(
+            name: impl AttributeValue,
+            value: impl AttributeValue,
+            checked: bool,
+            attrs: impl Attributes,
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::checkbox(name, value, checked, attrs))
+        }
+
+        pub fn date(
+            name: impl AttributeValue, attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::date(name, attrs))
+        }
+
+        pub fn email(
+            name: impl AttributeValue, attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::email(name, attrs))
+        }
+
+        pub fn file(
+            name: impl AttributeValue,
+            accept: impl AttributeValue,
+            attrs: impl Attributes,
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::file(name, accept, attrs))
+        }
+
+        pub fn hidden(
+            name: impl AttributeValue, value: impl AttributeValue
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::hidden(name, value))
+        }
+
+        pub fn number(
+            name: impl AttributeValue,
+            min: Option<f64>,
+            max: Option<f64>,
+            step: Option<f64>,
+            attrs: impl Attributes,
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::number(name, min, max, step, attrs))
+        }
+
+        pub fn text(
+            name: impl AttributeValue, attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(base::text(name, attrs))
+        }
+    }
+
+    //--- kbd
+
+    standard!(kbd: PhrasingContent(Phrasing));
+
+    //--- label
+
+    pub fn label(
+        for_: impl AttributeValue, content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::label(for_, content))
+    }
+
+    //--- li
+
+    standard!(li: ListItem(Flow));
+
+    //--- main
+
+    standard!(main: FlowContent(Flow));
+
+    //--- mark
+
+    standard!(mark: PhrasingContent(Phrasing));
+
+    //--- math
+
+    /// Creates the root element of an inline MathML formula.
+    ///
+    /// The content is MathML and thus not subject to the HTML categories.
+    pub fn math(content: impl Content) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(crate::mathml::math(content))
+    }
+
+    //--- menu
+
+    standard!(menu: FlowContent(ListItems));
+
+    //--- meter
+
+    pub fn meter(
+        value: f64, min: f64, max: f64, content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::meter(value, min, max, content))
+    }
+
+    //--- nav
+
+    standard!(nav: FlowContent(Flow));
+
+    //--- noscript
+
+    standard!(noscript: PhrasingContent(Phrasing));
+
+    //--- object
+
+    pub fn object(
+        data: impl AttributeValue,
+        mime_type: impl AttributeValue,
+        fallback: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::object(data, mime_type, fallback))
+    }
+
+    pub mod object {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes, fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::object::attrs(attrs, fallback))
+        }
+    }
+
+    //--- ol
+
+    standard!(ol: FlowContent(ListItems));
+
+    //--- optgroup, option
+
+    pub fn optgroup(
+        label: impl AttributeValue, content: impl OptionItems
+    ) -> OptionGroup<impl Content> {
+        OptionGroup::new(super::optgroup(label, content))
+    }
+
+    pub fn option(
+        value: impl AttributeValue, selected: bool, label: impl Text
+    ) -> OptionItem<impl Content> {
+        OptionItem::new(super::option(value, selected, label))
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes, label: impl Text
+        ) -> OptionItem<impl Content> {
+            OptionItem::new(crate::html::option::attrs(attrs, label))
+        }
+    }
+
+    //--- output
+
+    pub fn output(
+        name: impl AttributeValue, content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::output(name, content))
+    }
+
+    //--- p
+
+    standard!(p: FlowContent(Phrasing));
+
+    //--- picture
+
+    /// Creates a picture element.
+    ///
+    /// The image should be an `img` element used if none of the sources
+    /// apply.
+    pub fn picture(
+        sources: impl MediaSources, img: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::picture((sources, img)))
+    }
+
+    //--- pre
+
+    standard!(pre: FlowContent(Phrasing));
+
+    //--- progress
+
+    pub fn progress(
+        value: f64, max: f64, content: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::progress(value, max, content))
+    }
+
+    //--- q
+
+    standard!(q: PhrasingContent(Phrasing) {
+        pub fn cite<'a>(
+            cite: &'a url::Url, content: impl Phrasing + 'a
+        ) -> PhrasingContent<impl Content + 'a> {
+            PhrasingContent::new(crate::html::q::cite(cite, content))
+        }
+    });
+
+    //--- s
+
+    standard!(s: PhrasingContent(Phrasing));
+
+    //--- samp
+
+    standard!(samp: PhrasingContent(Phrasing));
+
+    //--- script
+
+    /// Creates a classic script loaded from a URL.
+    pub fn script(
+        src: impl AttributeValue
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::script(src))
+    }
+
+    pub mod script {
+        use super::*;
+
+        /// Creates a script loaded from a URL as a JavaScript module.
+        pub fn module(
+            src: impl AttributeValue
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::script::module(src))
+        }
+
+        /// Creates an inline script.
+        pub fn inline(
+            code: impl AsRef<str>
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::script::inline(code))
+        }
+    }
+
+    //--- section
+
+    standard!(section: FlowContent(Flow));
+
+    //--- select
+
+    pub fn select(
+        name: impl AttributeValue, content: impl SelectItems
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::select(name, content))
+    }
+
+    pub mod select {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes, content: impl SelectItems
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::select::attrs(attrs, content))
+        }
+    }
+
+    //--- small
+
+    standard!(small: PhrasingContent(Phrasing));
+
+    //--- source
+
+    pub fn source(attrs: impl Attributes) -> MediaSource<impl Content> {
+        MediaSource::new(super::source(attrs))
+    }
+
+    pub mod source {
+        use super::*;
+
+        /// Creates a media resource for a `video` or `audio` element.
+        pub fn src(
+            src: impl AttributeValue, mime_type: impl AttributeValue
+        ) -> MediaSource<impl Content> {
+            MediaSource::new(crate::html::source::src(src, mime_type))
+        }
+
+        /// Creates an image source for a `picture` element.
+        pub fn srcset(
+            srcset: impl Attributes, media: impl AttributeValue
+        ) -> MediaSource<impl Content> {
+            MediaSource::new(crate::html::source::srcset(srcset, media))
+        }
+    }
+
+    //--- span
+
+    standard!(span: PhrasingContent(Phrasing));
+
+    //--- strong
+
+    standard!(strong: PhrasingContent(Phrasing));
+
+    //--- sub, sup
+
+    standard!(sub: PhrasingContent(Phrasing));
+    standard!(sup: PhrasingContent(Phrasing));
+
+    //--- svg
+
+    pub use crate::svg::svg;
+
+    //--- table
+
+    standard!(table: FlowContent(TableRows));
+
+    //--- td
+
+    standard!(td: TableCell(Flow));
+
+    //--- template
+
+    /// Creates a template element.
+    ///
+    /// The content is inert and only parsed, so it isn’t checked against
+    /// the categories.
+    pub fn template(content: impl Content) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::template(content))
+    }
+
+    pub mod template {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes, content: impl Content
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::template::attrs(attrs, content))
+        }
+
+        /// Creates a template that attaches a declarative shadow root.
+        pub fn shadow_root(
+            mode: attr::ShadowRootMode, content: impl Content
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::template::shadow_root(mode, content)
+            )
+        }
+    }
+
+    //--- textarea
+
+    pub fn textarea(
+        name: impl AttributeValue, content: impl Text
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::textarea(name, content))
+    }
+
+    //--- th
+
+    standard!(th: TableCell(Flow));
+
+    //--- time
+
+    standard!(time: PhrasingContent(Phrasing) {
+        pub fn datetime(
+            datetime: attr::Datetime, content: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::time::datetime(datetime, content)
+            )
+        }
+    });
+
+    //--- tr
+
+    standard!(tr: TableRow(TableCells));
+
+    //--- track
+
+    pub fn track(
+        kind: attr::TrackKind,
+        src: impl AttributeValue,
+        srclang: impl AttributeValue,
+        label: impl AttributeValue,
+    ) -> MediaSource<impl Content> {
+        MediaSource::new(super::track(kind, src, srclang, label))
+    }
+
+    pub mod track {
+        use super::*;
+
+        pub fn attrs(attrs: impl Attributes) -> MediaSource<impl Content> {
+            MediaSource::new(crate::html::track::attrs(attrs))
+        }
+    }
+
+    //--- u
+
+    standard!(u: PhrasingContent(Phrasing));
+
+    //--- ul
+
+    standard!(ul: FlowContent(ListItems));
+
+    //--- var
+
+    standard!(var: PhrasingContent(Phrasing));
+
+    //--- video
+
+    /// Creates a video element.
+    ///
+    /// The fallback is shown by browsers that don’t support the element.
+    pub fn video(
+        sources: impl MediaSources, fallback: impl Phrasing
+    ) -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::video((sources, fallback)))
+    }
+
+    pub mod video {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes,
+            sources: impl MediaSources,
+            fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(
+                crate::html::video::attrs(attrs, (sources, fallback))
+            )
+        }
+
+        /// Creates a video element with controls for a single source.
+        pub fn src(
+            src: impl AttributeValue, fallback: impl Phrasing
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::video::src(src, fallback))
+        }
+    }
+
+    //--- wbr
+
+    pub fn wbr() -> PhrasingContent<impl Content> {
+        PhrasingContent::new(super::wbr())
+    }
+
+    pub mod wbr {
+        use super::*;
+
+        pub fn attrs(
+            attrs: impl Attributes
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::wbr::attrs(attrs))
+        }
+
+        pub fn class<'a>(
+            class: impl Tokens<'a>
+        ) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::wbr::class(class))
+        }
+
+        pub fn id(id: impl AttributeValue) -> PhrasingContent<impl Content> {
+            PhrasingContent::new(crate::html::wbr::id(id))
+        }
+    }
+}
+
+
+//------------ Attributes ----------------------------------------------------
+
+pub mod attr {
+    use crate::core::{
+        Attr, AttributeName, Attributes, AttributeValue, BoolAttr, Target,
+        Tokens,
+    };
+    use std::fmt;
+    use crate::escape;
+    use crate::utils::display;
+
+    pub fn accept(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("accept", value)
+    }
+
+    /// Sets the key used to activate or focus the element.
+    pub fn accesskey(value: char) -> impl Attributes {
+        Attr::new("accesskey", display(value))
+    }
+
+    pub fn action(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("action", value)
+    }
+
+    pub fn allow(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("allow", value)
+    }
+
+    pub fn allowfullscreen(value: bool) -> impl Attributes {
+        BoolAttr::new("allowfullscreen", value)
+    }
+
+    pub fn alt(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("alt", value)
+    }
+
+    /// Creates an arbitrary `aria-*` attribute.
+    ///
+    /// Prefer the typed attributes of the [`aria`] module which only
+    /// allow the states and properties defined by WAI-ARIA.
+    pub fn aria(
+        key: impl AttributeName, value: impl AttributeValue
+    ) -> impl Attributes {
+        Attr::new(("aria-", key), value)
+    }
+
+    /// The states and properties of WAI-ARIA 1.2.
+    ///
+    /// Each function creates the `aria-` attribute of the same name.
+    /// Attributes that refer to other elements take their IDs.
+    pub mod aria {
+        use crate::core::{Attr, Attributes, AttributeValue, Target, Tokens};
+        use crate::utils::display;
+        use super::WsTokens;
+
+        /// Identifies the currently active descendant of a composite widget.
+        pub fn activedescendant(id: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-activedescendant", id)
+        }
+
+        pub fn atomic(value: bool) -> impl Attributes {
+            Attr::new("aria-atomic", bool_value(value))
+        }
+
+        pub fn autocomplete(value: Autocomplete) -> impl Attributes {
+            Attr::new("aria-autocomplete", value)
+        }
+
+        pub fn busy(value: bool) -> impl Attributes {
+            Attr::new("aria-busy", bool_value(value))
+        }
+
+        pub fn checked(value: Tristate) -> impl Attributes {
+            Attr::new("aria-checked", value)
+        }
+
+        /// Sets the number of columns, `-1` if unknown.
+        pub fn colcount(value: i64) -> impl Attributes {
+            Attr::new("aria-colcount", display(value))
+        }
+
+        pub fn colindex(value: u64) -> impl Attributes {
+            Attr::new("aria-colindex", display(value))
+        }
+
+        pub fn colindextext(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-colindextext", value)
+        }
+
+        pub fn colspan(value: u64) -> impl Attributes {
+            Attr::new("aria-colspan", display(value))
+        }
+
+        pub fn controls<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+            Attr::new("aria-controls", WsTokens(ids))
+        }
+
+        pub fn current(value: Current) -> impl Attributes {
+            Attr::new("aria-current", value)
+        }
+
+        pub fn describedby<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+            Attr::new("aria-describedby", WsTokens(ids))
+        }
+
+        pub fn description(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-description", value)
+        }
+
+        pub fn details(id: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-details", id)
+        }
+
+        pub fn disabled(value: bool) -> impl Attributes {
+            Attr::new("aria-disabled", bool_value(value))
+        }
+
+        pub fn errormessage(id: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-errormessage", id)
+        }
+
+        pub fn expanded(value: bool) -> impl Attributes {
+            Attr::new("aria-expanded", bool_value(value))
+        }
+
+        pub fn flowto<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+            Attr::new("aria-flowto", WsTokens(ids))
+        }
+
+        pub fn haspopup(value: HasPopup) -> impl Attributes {
+            Attr::new("aria-haspopup", value)
+        }
+
+        pub fn hidden(value: bool) -> impl Attributes {
+            Attr::new("aria-hidden", bool_value(value))
+        }
+
+        pub fn invalid(value: Invalid) -> impl Attributes {
+            Attr::new("aria-invalid", value)
+        }
+
+        pub fn keyshortcuts(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-keyshortcuts", value)
+        }
+
+        pub fn label(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-label", value)
+        }
+
+        pub fn labelledby<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+            Attr::new("aria-labelledby", WsTokens(ids))
+        }
+
+        pub fn level(value: u64) -> impl Attributes {
+            Attr::new("aria-level", display(value))
+        }
+
+        pub fn live(value: Live) -> impl Attributes {
+            Attr::new("aria-live", value)
+        }
+
+        pub fn modal(value: bool) -> impl Attributes {
+            Attr::new("aria-modal", bool_value(value))
+        }
+
+        pub fn multiline(value: bool) -> impl Attributes {
+            Attr::new("aria-multiline", bool_value(value))
+        }
+
+        pub fn multiselectable(value: bool) -> impl Attributes {
+            Attr::new("aria-multiselectable", bool_value(value))
+        }
+
+        pub fn orientation(value: Orientation) -> impl Attributes {
+            Attr::new("aria-orientation", value)
+        }
+
+        pub fn owns<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+            Attr::new("aria-owns", WsTokens(ids))
+        }
+
+        pub fn placeholder(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-placeholder", value)
+        }
+
+        pub fn posinset(value: u64) -> impl Attributes {
+            Attr::new("aria-posinset", display(value))
+        }
+
+        pub fn pressed(value: Tristate) -> impl Attributes {
+            Attr::new("aria-pressed", value)
+        }
+
+        pub fn readonly(value: bool) -> impl Attributes {
+            Attr::new("aria-readonly", bool_value(value))
+        }
+
+        pub fn relevant(
+            value: impl IntoIterator<Item = Relevant>
+        ) -> impl Attributes {
+            Attr::new("aria-relevant", RelevantTokens(value.into_iter()))
+        }
+
+        pub fn required(value: bool) -> impl Attributes {
+            Attr::new("aria-required", bool_value(value))
+        }
+
+        pub fn roledescription(
+            value: impl AttributeValue
+        ) -> impl Attributes {
+            Attr::new("aria-roledescription", value)
+        }
+
+        /// Sets the number of rows, `-1` if unknown.
+        pub fn rowcount(value: i64) -> impl Attributes {
+            Attr::new("aria-rowcount", display(value))
+        }
+
+        pub fn rowindex(value: u64) -> impl Attributes {
+            Attr::new("aria-rowindex", display(value))
+        }
+
+        pub fn rowindextext(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-rowindextext", value)
+        }
+
+        pub fn rowspan(value: u64) -> impl Attributes {
+            Attr::new("aria-rowspan", display(value))
+        }
+
+        pub fn selected(value: bool) -> impl Attributes {
+            Attr::new("aria-selected", bool_value(value))
+        }
+
+        /// Sets the number of items in the set, `-1` if unknown.
+        pub fn setsize(value: i64) -> impl Attributes {
+            Attr::new("aria-setsize", display(value))
+        }
+
+        pub fn sort(value: Sort) -> impl Attributes {
+            Attr::new("aria-sort", value)
+        }
+
+        pub fn valuemax(value: f64) -> impl Attributes {
+            Attr::new("aria-valuemax", display(value))
+        }
+
+        pub fn valuemin(value: f64) -> impl Attributes {
+            Attr::new("aria-valuemin", display(value))
+        }
+
+        pub fn valuenow(value: f64) -> impl Attributes {
+            Attr::new("aria-valuenow", display(value))
+        }
+
+        pub fn valuetext(value: impl AttributeValue) -> impl Attributes {
+            Attr::new("aria-valuetext", value)
+        }
+
+
+        //--- Autocomplete
+
+        /// How input to a widget is completed.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Autocomplete {
+            Inline,
+            List,
+            Both,
+            None,
+        }
+
+        impl AttributeValue for Autocomplete {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Autocomplete::Inline => b"inline",
+                    Autocomplete::List => b"list",
+                    Autocomplete::Both => b"both",
+                    Autocomplete::None => b"none",
+                })
+            }
+        }
+
+
+        //--- Current
+
+        /// Which kind of current item an element represents.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Current {
+            Page,
+            Step,
+            Location,
+            Date,
+            Time,
+            True,
+            False,
+        }
+
+        impl AttributeValue for Current {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Current::Page => b"page",
+                    Current::Step => b"step",
+                    Current::Location => b"location",
+                    Current::Date => b"date",
+                    Current::Time => b"time",
+                    Current::True => b"true",
+                    Current::False => b"false",
+                })
+            }
+        }
+
+
+        //--- HasPopup
+
+        /// The kind of popup an element triggers.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum HasPopup {
+            False,
+            True,
+            Menu,
+            Listbox,
+            Tree,
+            Grid,
+            Dialog,
+        }
+
+        impl AttributeValue for HasPopup {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    HasPopup::False => b"false",
+                    HasPopup::True => b"true",
+                    HasPopup::Menu => b"menu",
+                    HasPopup::Listbox => b"listbox",
+                    HasPopup::Tree => b"tree",
+                    HasPopup::Grid => b"grid",
+                    HasPopup::Dialog => b"dialog",
+                })
+            }
+        }
+
+
+        //--- Invalid
+
+        /// Whether and why the value of an element is invalid.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Invalid {
+            False,
+            True,
+            Grammar,
+            Spelling,
+        }
+
+        impl AttributeValue for Invalid {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Invalid::False => b"false",
+                    Invalid::True => b"true",
+                    Invalid::Grammar => b"grammar",
+                    Invalid::Spelling => b"spelling",
+                })
+            }
+        }
+
+
+        //--- Live
+
+        /// How updates to a live region are announced.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Live {
+            Off,
+            Polite,
+            Assertive,
+        }
+
+        impl AttributeValue for Live {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Live::Off => b"off",
+                    Live::Polite => b"polite",
+                    Live::Assertive => b"assertive",
+                })
+            }
+        }
+
+
+        //--- Orientation
+
+        /// The orientation of a widget.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Orientation {
+            Horizontal,
+            Vertical,
+        }
+
+        impl AttributeValue for Orientation {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Orientation::Horizontal => b"horizontal",
+                    Orientation::Vertical => b"vertical",
+                })
+            }
+        }
+
+
+        //--- Relevant
+
+        /// A kind of change in a live region that is announced.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Relevant {
+            Additions,
+            Removals,
+            Text,
+            All,
+        }
+
+        impl Relevant {
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    Relevant::Additions => "additions",
+                    Relevant::Removals => "removals",
+                    Relevant::Text => "text",
+                    Relevant::All => "all",
+                }
+            }
+        }
+
+        struct RelevantTokens<I>(I);
+
+        impl<I> AttributeValue for RelevantTokens<I>
+        where I: Iterator<Item = Relevant> {
+            fn render_attr_value(self, target: &mut Target) {
+                for (idx, item) in self.0.enumerate() {
+                    if idx > 0 {
+                        target.append_slice(b" ");
+                    }
+                    target.append_slice(item.as_str().as_bytes());
+                }
+            }
+        }
+
+
+        //--- Sort
+
+        /// The direction a table or grid is sorted in.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Sort {
+            Ascending,
+            Descending,
+            None,
+            Other,
+        }
+
+        impl AttributeValue for Sort {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Sort::Ascending => b"ascending",
+                    Sort::Descending => b"descending",
+                    Sort::None => b"none",
+                    Sort::Other => b"other",
+                })
+            }
+        }
+
+
+        //--- Tristate
+
+        /// The state of a checkbox or toggle button.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Tristate {
+            False,
+            True,
+            Mixed,
+        }
+
+        impl AttributeValue for Tristate {
+            fn render_attr_value(self, target: &mut Target) {
+                target.append_slice(match self {
+                    Tristate::False => b"false",
+                    Tristate::True => b"true",
+                    Tristate::Mixed => b"mixed",
+                })
+            }
+        }
+
+
+        //--- Helpers
+
+        fn bool_value(value: bool) -> &'static str {
+            if value { "true" } else { "false" }
+        }
+    }
+
+    /// Sets the kind of resource a preload link fetches.
+    pub fn as_(value: Destination) -> impl Attributes {
+        Attr::new("as", value)
+    }
+
+    pub fn async_(value: bool) -> impl Attributes {
+        BoolAttr::new("async", value)
+    }
+
+    pub fn autocapitalize(value: Autocapitalize) -> impl Attributes {
+        Attr::new("autocapitalize", value)
+    }
+
+    pub fn autofocus(value: bool) -> impl Attributes {
+        BoolAttr::new("autofocus", value)
+    }
+
+    pub fn autoplay(value: bool) -> impl Attributes {
+        BoolAttr::new("autoplay", value)
+    }
+
+    pub fn checked(value: bool) -> impl Attributes {
+        BoolAttr::new("checked", value)
+    }
+
+    /// Sets the URL of the source of a quotation or change.
+    pub fn cite(value: &url::Url) -> impl Attributes + '_ {
+        Attr::new("cite", value.as_str())
+    }
+
+    pub fn class<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("class", WsTokens(value))
+    }
+
+    pub fn cols(value: u64) -> impl Attributes {
+        Attr::new("cols", display(value))
+    }
+
+    pub fn contenteditable(value: ContentEditable) -> impl Attributes {
+        Attr::new("contenteditable", value)
+    }
+
+    pub fn controls(value: bool) -> impl Attributes {
+        BoolAttr::new("controls", value)
+    }
+
+    pub fn coords(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("coords", value)
+    }
+
+    pub fn crossorigin(value: CrossOrigin) -> impl Attributes {
+        Attr::new("crossorigin", value)
+    }
+
+    pub fn data(
+        key: impl AttributeName, value: impl AttributeValue
+    ) -> impl Attributes {
+        Attr::new(("data-", key), value)
+    }
+
+    pub fn datetime(value: Datetime) -> impl Attributes {
+        Attr::new("datetime", value)
+    }
+
+    pub fn decoding(value: Decoding) -> impl Attributes {
+        Attr::new("decoding", value)
+    }
+
+    pub fn default(value: bool) -> impl Attributes {
+        BoolAttr::new("default", value)
+    }
+
+    pub fn defer(value: bool) -> impl Attributes {
+        BoolAttr::new("defer", value)
+    }
+
+    pub fn dir(value: Dir) -> impl Attributes {
+        Attr::new("dir", value)
+    }
+
+    pub fn disabled(value: bool) -> impl Attributes {
+        BoolAttr::new("disabled", value)
+    }
+
+    /// Asks the browser to download the linked resource.
+    ///
+    /// The value is the suggested file name. If it is empty, the name is
+    /// taken from the URL.
+    pub fn download(filename: impl AttributeValue) -> impl Attributes {
+        Attr::new("download", filename)
+    }
+
+    pub fn draggable(value: bool) -> impl Attributes {
+        Attr::new("draggable", if value { "true" } else { "false" })
+    }
+
+    pub fn enterkeyhint(value: EnterKeyHint) -> impl Attributes {
+        Attr::new("enterkeyhint", value)
+    }
+
+    pub fn for_(id: impl AttributeValue) -> impl Attributes {
+        Attr::new("for", id)
+    }
+
+    pub fn formnovalidate(value: bool) -> impl Attributes {
+        BoolAttr::new("formnovalidate", value)
+    }
+
+    pub fn height(value: u64) -> impl Attributes {
+        Attr::new("height", display(value))
+    }
+
+    pub fn hidden(value: bool) -> impl Attributes {
+        BoolAttr::new("hidden", value)
+    }
+
+    pub fn href(id: impl AttributeValue) -> impl Attributes {
+        Attr::new("href", id)
+    }
+
+    pub fn hreflang(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("hreflang", value)
+    }
+
+    pub fn id(id: impl AttributeValue) -> impl Attributes {
+        Attr::new("id", id)
+    }
+
+    pub fn inputmode(value: InputMode) -> impl Attributes {
+        Attr::new("inputmode", value)
+    }
+
+    /// Sets the subresource integrity metadata, e.g., `sha384-…`.
+    pub fn integrity(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("integrity", value)
+    }
+
+    pub fn inert(value: bool) -> impl Attributes {
+        BoolAttr::new("inert", value)
+    }
+
+    /// Sets the name of the customized built-in element to use.
+    pub fn is(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("is", value)
+    }
+
+    pub fn ismap(value: bool) -> impl Attributes {
+        BoolAttr::new("ismap", value)
+    }
+
+    /// Sets the global identifier of a microdata item.
+    pub fn itemid(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("itemid", value)
+    }
+
+    pub fn itemprop<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("itemprop", WsTokens(value))
+    }
+
+    /// Adds the properties of the elements with the given ids to an item.
+    pub fn itemref<'a>(ids: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("itemref", WsTokens(ids))
+    }
+
+    pub fn itemscope(value: bool) -> impl Attributes {
+        BoolAttr::new("itemscope", value)
+    }
+
+    pub fn itemtype<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("itemtype", WsTokens(value))
+    }
+
+    pub fn kind(value: TrackKind) -> impl Attributes {
+        Attr::new("kind", value)
+    }
+
+    pub fn label(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("label", value)
+    }
+
+    pub fn lang(lang: impl AttributeValue) -> impl Attributes {
+        Attr::new("lang", lang)
+    }
+
+    pub fn list(id: impl AttributeValue) -> impl Attributes {
+        Attr::new("list", id)
+    }
+
+    pub fn loading(value: Loading) -> impl Attributes {
+        Attr::new("loading", value)
+    }
+
+    pub fn loop_(value: bool) -> impl Attributes {
+        BoolAttr::new("loop", value)
+    }
+
+    pub fn max(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("max", value)
+    }
+
+    pub fn maxlength(value: u64) -> impl Attributes {
+        Attr::new("maxlength", display(value))
+    }
+
+    pub fn media(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("media", value)
+    }
+
+    pub fn method(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("method", value)
+    }
+
+    pub fn min(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("min", value)
+    }
+
+    pub fn minlength(value: u64) -> impl Attributes {
+        Attr::new("minlength", display(value))
+    }
+
+    pub fn multiple(value: bool) -> impl Attributes {
+        BoolAttr::new("multiple", value)
+    }
+
+    pub fn muted(value: bool) -> impl Attributes {
+        BoolAttr::new("muted", value)
+    }
+
+    pub fn name(name: impl AttributeValue) -> impl Attributes {
+        Attr::new("name", name)
+    }
+
+    /// Sets the cryptographic nonce used by a Content Security Policy.
+    pub fn nonce(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("nonce", value)
+    }
+
+    pub fn nomodule(value: bool) -> impl Attributes {
+        BoolAttr::new("nomodule", value)
+    }
+
+    pub fn novalidate(value: bool) -> impl Attributes {
+        BoolAttr::new("novalidate", value)
+    }
+
+    /// Creates an event handler attribute for the given event.
+    ///
+    /// The event is given without the `on` prefix, e.g., `"click"` for
+    /// `onclick`.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if `event` isn't the lower case name of an
+    /// event that has an event handler attribute.
+    pub fn on(event: &'static str, handler: Js) -> impl Attributes {
+        assert!(
+            crate::check::is_event(event),
+            "unknown event handler 'on{}'", event
+        );
+        Attr::new(("on", event), handler)
+    }
+
+    pub fn onblur(handler: Js) -> impl Attributes {
+        on("blur", handler)
+    }
+
+    pub fn onchange(handler: Js) -> impl Attributes {
+        on("change", handler)
+    }
+
+    pub fn onclick(handler: Js) -> impl Attributes {
+        on("click", handler)
+    }
+
+    pub fn onfocus(handler: Js) -> impl Attributes {
+        on("focus", handler)
+    }
+
+    pub fn oninput(handler: Js) -> impl Attributes {
+        on("input", handler)
+    }
+
+    pub fn onkeydown(handler: Js) -> impl Attributes {
+        on("keydown", handler)
+    }
+
+    pub fn onload(handler: Js) -> impl Attributes {
+        on("load", handler)
+    }
+
+    pub fn onsubmit(handler: Js) -> impl Attributes {
+        on("submit", handler)
+    }
+
+    pub fn open(value: bool) -> impl Attributes {
+        BoolAttr::new("open", value)
+    }
+
+    /// Sets the names under which the element is exposed as a shadow part.
+    pub fn part<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("part", WsTokens(value))
+    }
+
+    pub fn pattern(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("pattern", value)
+    }
+
+    /// Sets the URLs to notify when the link is followed.
+    pub fn ping<'a>(urls: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("ping", WsTokens(urls))
+    }
+
+    pub fn placeholder(placeholder: impl AttributeValue) -> impl Attributes {
+        Attr::new("placeholder", placeholder)
+    }
+
+    pub fn playsinline(value: bool) -> impl Attributes {
+        BoolAttr::new("playsinline", value)
+    }
+
+    pub fn popover(value: Popover) -> impl Attributes {
+        Attr::new("popover", value)
+    }
+
+    pub fn poster(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("poster", value)
+    }
+
+    /// Creates an RDFa `prefix` attribute from prefixes and their IRIs.
+    pub fn prefix<'a>(
+        value: impl IntoIterator<Item = (&'a str, &'a str)>
+    ) -> impl Attributes {
+        Attr::new("prefix", Prefixes(value.into_iter()))
+    }
+
+    pub fn preload(value: Preload) -> impl Attributes {
+        Attr::new("preload", value)
+    }
+
+    /// Creates an RDFa `property` attribute.
+    pub fn property<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("property", WsTokens(value))
+    }
+
+    pub fn readonly(value: bool) -> impl Attributes {
+        BoolAttr::new("readonly", value)
+    }
+
+    pub fn referrerpolicy(value: ReferrerPolicy) -> impl Attributes {
+        Attr::new("referrerpolicy", value)
+    }
+
+    pub fn rel(value: impl IntoIterator<Item = Rel>) -> impl Attributes {
+        Attr::new("rel", RelTokens(value.into_iter()))
+    }
+
+    pub fn required(value: bool) -> impl Attributes {
+        BoolAttr::new("required", value)
+    }
+
+    /// Creates an RDFa `resource` attribute.
+    pub fn resource(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("resource", value)
+    }
+
+    pub fn reversed(value: bool) -> impl Attributes {
+        BoolAttr::new("reversed", value)
+    }
+
+    pub fn role(value: Role) -> impl Attributes {
+        Attr::new("role", value)
+    }
+
+    pub fn rows(value: u64) -> impl Attributes {
+        Attr::new("rows", display(value))
+    }
+
+    pub fn sandbox(
+        value: impl IntoIterator<Item = Sandbox>
+    ) -> impl Attributes {
+        Attr::new("sandbox", SandboxTokens(value.into_iter()))
+    }
+
+    pub fn selected(value: bool) -> impl Attributes {
+        BoolAttr::new("selected", value)
+    }
+
+    pub fn shadowrootclonable(value: bool) -> impl Attributes {
+        BoolAttr::new("shadowrootclonable", value)
+    }
+
+    pub fn shadowrootdelegatesfocus(value: bool) -> impl Attributes {
+        BoolAttr::new("shadowrootdelegatesfocus", value)
+    }
+
+    pub fn shadowrootmode(value: ShadowRootMode) -> impl Attributes {
+        Attr::new("shadowrootmode", value)
+    }
+
+    pub fn shadowrootserializable(value: bool) -> impl Attributes {
+        BoolAttr::new("shadowrootserializable", value)
+    }
+
+    pub fn shape(value: Shape) -> impl Attributes {
+        Attr::new("shape", value)
+    }
+
+    /// Creates a `sizes` attribute.
+    ///
+    /// The attribute consists of pairs of a media condition and the image
+    /// size to use if the condition matches, followed by the size to use if
+    /// none of the conditions match.
+    ///
+    /// # Panics
+    ///
+    /// The function panics if a media condition contains a comma. Commas
+    /// separate the entries of the attribute and can't be escaped, and a
+    /// media condition can't contain one anyway.
+    pub fn sizes<I, M>(sizes: I, default: Length) -> impl Attributes
+    where I: IntoIterator<Item = (M, Length)>, M: AsRef<str> {
+        let sizes = sizes.into_iter().inspect(|(condition, _)| {
+            assert!(
+                !condition.as_ref().contains(','),
+                "comma in media condition '{}'", condition.as_ref()
+            );
+        }).collect::<Vec<_>>();
+        Attr::new("sizes", Sizes(sizes.into_iter(), default))
+    }
+
+    /// Assigns the element to a named slot of a shadow tree.
+    pub fn slot(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("slot", value)
+    }
+
+    pub fn spellcheck(value: bool) -> impl Attributes {
+        Attr::new("spellcheck", if value { "true" } else { "false" })
+    }
+
+    pub fn src(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("src", value)
+    }
+
+    pub fn srclang(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("srclang", value)
+    }
+
+    /// Creates a `srcset` attribute from pairs of URL and descriptor.
+    ///
+    /// White space in the URLs as well as leading and trailing commas are
+    /// percent-encoded so that they don't confuse parsing of the attribute.
+    pub fn srcset<I, U>(candidates: I) -> impl Attributes
+    where I: IntoIterator<Item = (U, Descriptor)>, U: AsRef<str> {
+        Attr::new("srcset", Srcset(candidates.into_iter()))
+    }
+
+    pub fn start(value: i64) -> impl Attributes {
+        Attr::new("start", display(value))
+    }
+
+    pub fn step(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("step", value)
+    }
+
+    pub fn style(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("style", value)
+    }
+
+    pub fn tabindex(value: i64) -> impl Attributes {
+        Attr::new("tabindex", display(value))
+    }
+
+    pub fn target(value: LinkTarget<'_>) -> impl Attributes + '_ {
+        Attr::new("target", value)
+    }
+
+    pub fn title(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("title", value)
+    }
+
+    pub fn translate(value: bool) -> impl Attributes {
+        Attr::new("translate", if value { "yes" } else { "no" })
+    }
+
+    pub fn type_(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("type", value)
+    }
+
+    /// Creates an RDFa `typeof` attribute.
+    pub fn typeof_<'a>(value: impl Tokens<'a>) -> impl Attributes {
+        Attr::new("typeof", WsTokens(value))
+    }
+
+    pub fn usemap(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("usemap", value)
+    }
+
+    pub fn value(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("value", value)
+    }
+
+    /// Creates an RDFa `vocab` attribute.
+    pub fn vocab(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("vocab", value)
+    }
+
+    pub fn width(value: u64) -> impl Attributes {
+        Attr::new("width", display(value))
+    }
+
+
+    //--- Autocapitalize
+
+    /// How text entered by the user is capitalized.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Autocapitalize {
+        Off,
+        Sentences,
+        Words,
+        Characters,
+    }
+
+    impl AttributeValue for Autocapitalize {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Autocapitalize::Off => b"off",
+                Autocapitalize::Sentences => b"sentences",
+                Autocapitalize::Words => b"words",
+                Autocapitalize::Characters => b"characters",
+            })
+        }
+    }
+
+
+    //--- ContentEditable
+
+    /// Whether the user can edit an element.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ContentEditable {
+        True,
+        False,
+        PlaintextOnly,
+    }
+
+    impl AttributeValue for ContentEditable {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                ContentEditable::True => b"true",
+                ContentEditable::False => b"false",
+                ContentEditable::PlaintextOnly => b"plaintext-only",
+            })
+        }
+    }
+
+
+    //--- CrossOrigin
+
+    /// The CORS mode used to fetch a resource.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum CrossOrigin {
+        /// Requests are sent without credentials for other origins.
+        Anonymous,
+
+        /// Requests are always sent with credentials.
+        UseCredentials,
+    }
+
+    impl AttributeValue for CrossOrigin {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                CrossOrigin::Anonymous => b"anonymous",
+                CrossOrigin::UseCredentials => b"use-credentials",
+            })
+        }
+    }
+
+
+    //--- Datetime
+
+    /// A machine-readable date, time, or duration.
+    ///
+    /// Use this as the value of the `datetime` attribute of the `time`,
+    /// `del`, and `ins` elements.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Datetime {
+        /// A year, e.g., `2025`.
+        Year(u16),
+
+        /// A month of a year, e.g., `2025-05`.
+        Month(u16, u8),
+
+        /// A date, e.g., `2025-05-01`.
+        Date(Date),
+
+        /// A time of day, e.g., `19:00`.
+        Time(Time),
+
+        /// A date and time without time zone, e.g., `2025-05-01T19:00`.
+        Local(Date, Time),
+
+        /// A date and time with the offset from UTC in minutes.
+        ///
+        /// For instance, `2025-05-01T19:00+02:00` has an offset of 120.
+        /// An offset of zero is rendered as `Z`.
+        Global(Date, Time, i16),
+
+        /// A duration, e.g., `PT90S`.
+        Duration(std::time::Duration),
+    }
+
+    impl AttributeValue for Datetime {
+        fn render_attr_value(self, target: &mut Target) {
+            match self {
+                Datetime::Year(year) => {
+                    format_args!("{:04}", year).render_attr_value(target)
+                }
+                Datetime::Month(year, month) => {
+                    format_args!(
+                        "{:04}-{:02}", year, month
+                    ).render_attr_value(target)
+                }
+                Datetime::Date(date) => {
+                    format_args!("{}", date).render_attr_value(target)
+                }
+                Datetime::Time(time) => {
+                    format_args!("{}", time).render_attr_value(target)
+                }
+                Datetime::Local(date, time) => {
+                    format_args!(
+                        "{}T{}", date, time
+                    ).render_attr_value(target)
+                }
+                Datetime::Global(date, time, 0) => {
+                    format_args!(
+                        "{}T{}Z", date, time
+                    ).render_attr_value(target)
+                }
+                Datetime::Global(date, time, offset) => {
+                    format_args!(
+                        "{}T{}{}{:02}:{:02}",
+                        date, time, if offset < 0 { '-' } else { '+' },
+                        offset.unsigned_abs() / 60,
+                        offset.unsigned_abs() % 60,
+                    ).render_attr_value(target)
+                }
+                Datetime::Duration(duration) => {
+                    match duration.subsec_millis() {
+                        0 => format_args!(
+                            "PT{}S", duration.as_secs()
+                        ).render_attr_value(target),
+                        millis => format_args!(
+                            "PT{}.{:03}S", duration.as_secs(), millis
+                        ).render_attr_value(target),
+                    }
+                }
+            }
+        }
+    }
+
+    /// A date for a [`Datetime`].
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Date {
+        pub year: u16,
+        pub month: u8,
+        pub day: u8,
+    }
+
+    impl Date {
+        pub const fn new(year: u16, month: u8, day: u8) -> Self {
+            Date { year, month, day }
+        }
+    }
+
+    impl fmt::Display for Date {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+
+    /// A time of day for a [`Datetime`].
+    ///
+    /// The seconds are left out when rendering if they are zero.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Time {
+        pub hour: u8,
+        pub minute: u8,
+        pub second: u8,
+    }
+
+    impl Time {
+        pub const fn new(hour: u8, minute: u8, second: u8) -> Self {
+            Time { hour, minute, second }
+        }
+    }
+
+    impl fmt::Display for Time {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+            if self.second != 0 {
+                write!(f, ":{:02}", self.second)?;
+            }
+            Ok(())
+        }
+    }
+
+
+    //--- Decoding
+
+    /// How an image should be decoded.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Decoding {
+        Sync,
+        Async,
+        Auto,
+    }
+
+    impl AttributeValue for Decoding {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Decoding::Sync => b"sync",
+                Decoding::Async => b"async",
+                Decoding::Auto => b"auto",
+            })
+        }
+    }
+
+
+    //--- Descriptor
+
+    /// The descriptor of an image candidate in a `srcset` attribute.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Descriptor {
+        /// The width of the image in pixels, e.g., `480w`.
+        Width(u32),
+
+        /// The pixel density of the image, e.g., `2x`.
+        Density(f32),
+    }
+
+    impl AttributeValue for Descriptor {
+        fn render_attr_value(self, target: &mut Target) {
+            match self {
+                Descriptor::Width(width) => {
+                    format_args!("{}w", width).render_attr_value(target)
+                }
+                Descriptor::Density(density) => {
+                    format_args!("{}x", density).render_attr_value(target)
+                }
+            }
+        }
+    }
+
+
+    //--- Destination
+
+    /// The kind of resource fetched by a preload link.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Destination {
+        Audio,
+        Document,
+        Embed,
+        Fetch,
+        Font,
+        Image,
+        Object,
+        Script,
+        Style,
+        Track,
+        Video,
+        Worker,
+    }
+
+    impl AttributeValue for Destination {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Destination::Audio => b"audio",
+                Destination::Document => b"document",
+                Destination::Embed => b"embed",
+                Destination::Fetch => b"fetch",
+                Destination::Font => b"font",
+                Destination::Image => b"image",
+                Destination::Object => b"object",
+                Destination::Script => b"script",
+                Destination::Style => b"style",
+                Destination::Track => b"track",
+                Destination::Video => b"video",
+                Destination::Worker => b"worker",
+            })
+        }
+    }
+
+
+    //--- Dir
+
+    /// The direction of text.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Dir {
+        Ltr,
+        Rtl,
+        Auto,
+    }
+
+    impl AttributeValue for Dir {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Dir::Ltr => b"ltr",
+                Dir::Rtl => b"rtl",
+                Dir::Auto => b"auto",
+            })
+        }
+    }
+
+
+    //--- EnterKeyHint
+
+    /// The label of the enter key on virtual keyboards.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum EnterKeyHint {
+        Enter,
+        Done,
+        Go,
+        Next,
+        Previous,
+        Search,
+        Send,
+    }
+
+    impl AttributeValue for EnterKeyHint {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                EnterKeyHint::Enter => b"enter",
+                EnterKeyHint::Done => b"done",
+                EnterKeyHint::Go => b"go",
+                EnterKeyHint::Next => b"next",
+                EnterKeyHint::Previous => b"previous",
+                EnterKeyHint::Search => b"search",
+                EnterKeyHint::Send => b"send",
+            })
+        }
+    }
+
+
+    //--- InputMode
+
+    /// The kind of virtual keyboard to show for an element.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum InputMode {
+        None,
+        Text,
+        Decimal,
+        Numeric,
+        Tel,
+        Search,
+        Email,
+        Url,
+    }
+
+    impl AttributeValue for InputMode {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                InputMode::None => b"none",
+                InputMode::Text => b"text",
+                InputMode::Decimal => b"decimal",
+                InputMode::Numeric => b"numeric",
+                InputMode::Tel => b"tel",
+                InputMode::Search => b"search",
+                InputMode::Email => b"email",
+                InputMode::Url => b"url",
+            })
+        }
     }
-}
 
-//--- nav
 
-standard!(nav);
+    //--- Js
 
-//--- p
+    /// JavaScript code for an event handler attribute.
+    ///
+    /// Handler code can only be created from static strings, i.e., from
+    /// code that is part of the program. Values only known at runtime are
+    /// passed as arguments of a function call via [`Js::call`] which
+    /// renders them as escaped JavaScript literals.
+    #[derive(Clone, Debug)]
+    pub struct Js {
+        code: String,
+    }
 
-standard!(p);
+    impl Js {
+        pub fn new(code: &'static str) -> Self {
+            Js { code: code.into() }
+        }
 
-//--- span
+        /// Creates a call of a function with the given arguments.
+        pub fn call<'a>(
+            function: &'static str,
+            args: impl IntoIterator<Item = JsValue<'a>>
+        ) -> Self {
+            let mut code = String::from(function);
+            code.push('(');
+            for (idx, arg) in args.into_iter().enumerate() {
+                if idx > 0 {
+                    code.push_str(", ");
+                }
+                arg.write(&mut code);
+            }
+            code.push(')');
+            Js { code }
+        }
 
-standard!(span);
+        /// Appends the code of another handler as a new statement.
+        pub fn then(mut self, other: Js) -> Self {
+            self.code.push_str("; ");
+            self.code.push_str(&other.code);
+            self
+        }
+    }
 
-//--- table
+    impl AttributeValue for Js {
+        fn render_attr_value(self, target: &mut Target) {
+            escape::render_attr(&self.code, target)
+        }
+    }
 
-standard!(table);
+    /// A value passed to a function in a [`Js`] handler.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum JsValue<'a> {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(&'a str),
+    }
 
-//--- td
+    impl JsValue<'_> {
+        fn write(self, code: &mut String) {
+            use std::fmt::Write;
 
-standard!(td);
+            match self {
+                JsValue::Null => code.push_str("null"),
+                JsValue::Bool(value) => {
+                    code.push_str(if value { "true" } else { "false" })
+                }
+                JsValue::Number(value) if value.is_nan() => {
+                    code.push_str("NaN")
+                }
+                JsValue::Number(value) if value.is_infinite() => {
+                    code.push_str(
+                        if value > 0. { "Infinity" } else { "-Infinity" }
+                    )
+                }
+                JsValue::Number(value) => {
+                    write!(code, "{}", value).unwrap()
+                }
+                JsValue::String(value) => {
+                    code.push('"');
+                    for ch in value.chars() {
+                        match ch {
+                            '"' => code.push_str("\\\""),
+                            '\\' => code.push_str("\\\\"),
+                            '\n' => code.push_str("\\n"),
+                            '\r' => code.push_str("\\r"),
+                            '\t' => code.push_str("\\t"),
+                            // Keep the code harmless should it ever end
+                            // up in a script element.
+                            ch if ch.is_control() || matches!(
+                                ch, '<' | '>' | '&' | '\'' | '\u{2028}'
+                                    | '\u{2029}'
+                            ) => {
+                                write!(code, "\\u{:04x}", ch as u32)
+                                    .unwrap()
+                            }
+                            ch => code.push(ch),
+                        }
+                    }
+                    code.push('"');
+                }
+            }
+        }
+    }
 
-//--- title
+    impl<'a> From<&'a str> for JsValue<'a> {
+        fn from(value: &'a str) -> Self {
+            JsValue::String(value)
+        }
+    }
 
-pub fn title(title: impl Text) -> impl Content {
-    TextElement::new("title", (), title)
-}
+    impl From<bool> for JsValue<'_> {
+        fn from(value: bool) -> Self {
+            JsValue::Bool(value)
+        }
+    }
 
-//--- tr
+    impl From<f64> for JsValue<'_> {
+        fn from(value: f64) -> Self {
+            JsValue::Number(value)
+        }
+    }
 
-standard!(tr);
+    impl From<i32> for JsValue<'_> {
+        fn from(value: i32) -> Self {
+            JsValue::Number(value.into())
+        }
+    }
 
-//--- tt
 
-pub fn tt(
-    content: impl Content
-) -> impl Content {
-    Element::new("tt", (), content)
-}
+    //--- Length
 
-//--- ul
+    /// The size of an image in a `sizes` attribute.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Length {
+        /// A size in CSS pixels.
+        Px(u32),
 
-standard!(ul);
+        /// A size in percent of the viewport width.
+        Vw(f32),
 
+        /// A size relative to the font size of the element.
+        Em(f32),
 
-//------------ Attributes ----------------------------------------------------
+        /// A size relative to the font size of the root element.
+        Rem(f32),
+    }
 
-pub mod attr {
-    use crate::core::{
-        Attr, AttributeName, Attributes, AttributeValue, Target,
-        Tokens,
-    };
-    use crate::escape;
-    use crate::utils::display;
+    impl AttributeValue for Length {
+        fn render_attr_value(self, target: &mut Target) {
+            match self {
+                Length::Px(value) => {
+                    format_args!("{}px", value).render_attr_value(target)
+                }
+                Length::Vw(value) => {
+                    format_args!("{}vw", value).render_attr_value(target)
+                }
+                Length::Em(value) => {
+                    format_args!("{}em", value).render_attr_value(target)
+                }
+                Length::Rem(value) => {
+                    format_args!("{}rem", value).render_attr_value(target)
+                }
+            }
+        }
+    }
 
-    pub fn action(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("action", value)
+
+    //--- LinkTarget
+
+    /// The browsing context a link or form opens in.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum LinkTarget<'a> {
+        /// A new tab or window.
+        Blank,
+
+        /// The current browsing context.
+        Self_,
+
+        /// The parent of the current browsing context.
+        Parent,
+
+        /// The topmost browsing context.
+        Top,
+
+        /// The browsing context with the given name.
+        Named(&'a str),
     }
 
-    pub fn alt(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("alt", value)
+    impl AttributeValue for LinkTarget<'_> {
+        fn render_attr_value(self, target: &mut Target) {
+            match self {
+                LinkTarget::Blank => target.append_slice(b"_blank"),
+                LinkTarget::Self_ => target.append_slice(b"_self"),
+                LinkTarget::Parent => target.append_slice(b"_parent"),
+                LinkTarget::Top => target.append_slice(b"_top"),
+                LinkTarget::Named(name) => escape::render_attr(name, target),
+            }
+        }
     }
 
-    pub fn aria(
-        key: impl AttributeName, value: impl AttributeValue
-    ) -> impl Attributes {
-        Attr::new(("aria-", key), value)
+
+    //--- ListType
+
+    /// The kind of marker used by an ordered list.
+    ///
+    /// Use this as the value of the `type` attribute of an `ol` element.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ListType {
+        Decimal,
+        LowerAlpha,
+        UpperAlpha,
+        LowerRoman,
+        UpperRoman,
     }
 
-    pub fn class<'a>(value: impl Tokens<'a>) -> impl Attributes {
-        Attr::new("class", WsTokens(value))
+    impl AttributeValue for ListType {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                ListType::Decimal => b"1",
+                ListType::LowerAlpha => b"a",
+                ListType::UpperAlpha => b"A",
+                ListType::LowerRoman => b"i",
+                ListType::UpperRoman => b"I",
+            })
+        }
     }
 
-    pub fn data(
-        key: impl AttributeName, value: impl AttributeValue
-    ) -> impl Attributes {
-        Attr::new(("data-", key), value)
+
+    //--- Loading
+
+    /// When an image or iframe should be loaded.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Loading {
+        Eager,
+        Lazy,
     }
 
-    pub fn href(id: impl AttributeValue) -> impl Attributes {
-        Attr::new("href", id)
+    impl AttributeValue for Loading {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Loading::Eager => b"eager",
+                Loading::Lazy => b"lazy",
+            })
+        }
     }
 
-    pub fn id(id: impl AttributeValue) -> impl Attributes {
-        Attr::new("id", id)
+
+    //--- Popover
+
+    /// How a popover element is shown and dismissed.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Popover {
+        Auto,
+        Manual,
+        Hint,
     }
 
-    pub fn lang(lang: impl AttributeValue) -> impl Attributes {
-        Attr::new("lang", lang)
+    impl AttributeValue for Popover {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Popover::Auto => b"auto",
+                Popover::Manual => b"manual",
+                Popover::Hint => b"hint",
+            })
+        }
     }
 
-    pub fn method(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("method", value)
+
+    //--- Prefixes
+
+    struct Prefixes<I>(I);
+
+    impl<'a, I> AttributeValue for Prefixes<I>
+    where I: Iterator<Item = (&'a str, &'a str)> {
+        fn render_attr_value(self, target: &mut Target) {
+            for (idx, (prefix, iri)) in self.0.enumerate() {
+                if idx > 0 {
+                    target.append_slice(b" ");
+                }
+                escape::render_attr(prefix, target);
+                target.append_slice(b": ");
+                escape::render_attr(iri, target);
+            }
+        }
     }
 
-    pub fn name(name: impl AttributeValue) -> impl Attributes {
-        Attr::new("name", name)
+
+    //--- Preload
+
+    /// How much of a media resource should be loaded in advance.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Preload {
+        None,
+        Metadata,
+        Auto,
     }
 
-    pub fn placeholder(placeholder: impl AttributeValue) -> impl Attributes {
-        Attr::new("placeholder", placeholder)
+    impl AttributeValue for Preload {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Preload::None => b"none",
+                Preload::Metadata => b"metadata",
+                Preload::Auto => b"auto",
+            })
+        }
     }
 
-    pub fn src(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("src", value)
+
+    //--- ReferrerPolicy
+
+    /// Which referrer information is sent when fetching a resource.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ReferrerPolicy {
+        NoReferrer,
+        NoReferrerWhenDowngrade,
+        Origin,
+        OriginWhenCrossOrigin,
+        SameOrigin,
+        StrictOrigin,
+        StrictOriginWhenCrossOrigin,
+        UnsafeUrl,
     }
 
-    pub fn title(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("title", value)
+    impl AttributeValue for ReferrerPolicy {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                ReferrerPolicy::NoReferrer => b"no-referrer",
+                ReferrerPolicy::NoReferrerWhenDowngrade => {
+                    b"no-referrer-when-downgrade"
+                }
+                ReferrerPolicy::Origin => b"origin",
+                ReferrerPolicy::OriginWhenCrossOrigin => {
+                    b"origin-when-cross-origin"
+                }
+                ReferrerPolicy::SameOrigin => b"same-origin",
+                ReferrerPolicy::StrictOrigin => b"strict-origin",
+                ReferrerPolicy::StrictOriginWhenCrossOrigin => {
+                    b"strict-origin-when-cross-origin"
+                }
+                ReferrerPolicy::UnsafeUrl => b"unsafe-url",
+            })
+        }
     }
 
-    pub fn type_(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("type", value)
+
+    //--- Rel
+
+    /// A link type of a hyperlink.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Rel {
+        Alternate,
+        Author,
+        Bookmark,
+        External,
+        Help,
+        License,
+        Me,
+        Next,
+        Nofollow,
+        Noopener,
+        Noreferrer,
+        Opener,
+        Prev,
+        Privacy,
+        Search,
+        Sponsored,
+        Tag,
+        Terms,
+        Ugc,
     }
 
-    pub fn value(value: impl AttributeValue) -> impl Attributes {
-        Attr::new("value", value)
+    impl Rel {
+        pub fn as_str(self) -> &'static str {
+            match self {
+                Rel::Alternate => "alternate",
+                Rel::Author => "author",
+                Rel::Bookmark => "bookmark",
+                Rel::External => "external",
+                Rel::Help => "help",
+                Rel::License => "license",
+                Rel::Me => "me",
+                Rel::Next => "next",
+                Rel::Nofollow => "nofollow",
+                Rel::Noopener => "noopener",
+                Rel::Noreferrer => "noreferrer",
+                Rel::Opener => "opener",
+                Rel::Prev => "prev",
+                Rel::Privacy => "privacy-policy",
+                Rel::Search => "search",
+                Rel::Sponsored => "sponsored",
+                Rel::Tag => "tag",
+                Rel::Terms => "terms-of-service",
+                Rel::Ugc => "ugc",
+            }
+        }
     }
 
-    pub fn width(value: u64) -> impl Attributes {
-        Attr::new("src", display(value))
+    struct RelTokens<I>(I);
+
+    impl<I: Iterator<Item = Rel>> AttributeValue for RelTokens<I> {
+        fn render_attr_value(self, target: &mut Target) {
+            for (idx, item) in self.0.enumerate() {
+                if idx > 0 {
+                    target.append_slice(b" ");
+                }
+                target.append_slice(item.as_str().as_bytes());
+            }
+        }
+    }
+
+
+    //--- Role
+
+    /// A WAI-ARIA role.
+    ///
+    /// This covers all non-abstract roles of WAI-ARIA 1.2.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Role {
+        Alert,
+        AlertDialog,
+        Application,
+        Article,
+        Banner,
+        BlockQuote,
+        Button,
+        Caption,
+        Cell,
+        Checkbox,
+        Code,
+        ColumnHeader,
+        ComboBox,
+        Complementary,
+        ContentInfo,
+        Definition,
+        Deletion,
+        Dialog,
+        Document,
+        Emphasis,
+        Feed,
+        Figure,
+        Form,
+        Generic,
+        Grid,
+        GridCell,
+        Group,
+        Heading,
+        Img,
+        Insertion,
+        Link,
+        List,
+        ListBox,
+        ListItem,
+        Log,
+        Main,
+        Marquee,
+        Math,
+        Menu,
+        MenuBar,
+        MenuItem,
+        MenuItemCheckbox,
+        MenuItemRadio,
+        Meter,
+        Navigation,
+        None,
+        Note,
+        Option,
+        Paragraph,
+        Presentation,
+        ProgressBar,
+        Radio,
+        RadioGroup,
+        Region,
+        Row,
+        RowGroup,
+        RowHeader,
+        ScrollBar,
+        Search,
+        SearchBox,
+        Separator,
+        Slider,
+        SpinButton,
+        Status,
+        Strong,
+        Subscript,
+        Superscript,
+        Switch,
+        Tab,
+        Table,
+        TabList,
+        TabPanel,
+        Term,
+        TextBox,
+        Time,
+        Timer,
+        ToolBar,
+        ToolTip,
+        Tree,
+        TreeGrid,
+        TreeItem,
+    }
+
+    impl Role {
+        pub fn as_str(self) -> &'static str {
+            match self {
+                Role::Alert => "alert",
+                Role::AlertDialog => "alertdialog",
+                Role::Application => "application",
+                Role::Article => "article",
+                Role::Banner => "banner",
+                Role::BlockQuote => "blockquote",
+                Role::Button => "button",
+                Role::Caption => "caption",
+                Role::Cell => "cell",
+                Role::Checkbox => "checkbox",
+                Role::Code => "code",
+                Role::ColumnHeader => "columnheader",
+                Role::ComboBox => "combobox",
+                Role::Complementary => "complementary",
+                Role::ContentInfo => "contentinfo",
+                Role::Definition => "definition",
+                Role::Deletion => "deletion",
+                Role::Dialog => "dialog",
+                Role::Document => "document",
+                Role::Emphasis => "emphasis",
+                Role::Feed => "feed",
+                Role::Figure => "figure",
+                Role::Form => "form",
+                Role::Generic => "generic",
+                Role::Grid => "grid",
+                Role::GridCell => "gridcell",
+                Role::Group => "group",
+                Role::Heading => "heading",
+                Role::Img => "img",
+                Role::Insertion => "insertion",
+                Role::Link => "link",
+                Role::List => "list",
+                Role::ListBox => "listbox",
+                Role::ListItem => "listitem",
+                Role::Log => "log",
+                Role::Main => "main",
+                Role::Marquee => "marquee",
+                Role::Math => "math",
+                Role::Menu => "menu",
+                Role::MenuBar => "menubar",
+                Role::MenuItem => "menuitem",
+                Role::MenuItemCheckbox => "menuitemcheckbox",
+                Role::MenuItemRadio => "menuitemradio",
+                Role::Meter => "meter",
+                Role::Navigation => "navigation",
+                Role::None => "none",
+                Role::Note => "note",
+                Role::Option => "option",
+                Role::Paragraph => "paragraph",
+                Role::Presentation => "presentation",
+                Role::ProgressBar => "progressbar",
+                Role::Radio => "radio",
+                Role::RadioGroup => "radiogroup",
+                Role::Region => "region",
+                Role::Row => "row",
+                Role::RowGroup => "rowgroup",
+                Role::RowHeader => "rowheader",
+                Role::ScrollBar => "scrollbar",
+                Role::Search => "search",
+                Role::SearchBox => "searchbox",
+                Role::Separator => "separator",
+                Role::Slider => "slider",
+                Role::SpinButton => "spinbutton",
+                Role::Status => "status",
+                Role::Strong => "strong",
+                Role::Subscript => "subscript",
+                Role::Superscript => "superscript",
+                Role::Switch => "switch",
+                Role::Tab => "tab",
+                Role::Table => "table",
+                Role::TabList => "tablist",
+                Role::TabPanel => "tabpanel",
+                Role::Term => "term",
+                Role::TextBox => "textbox",
+                Role::Time => "time",
+                Role::Timer => "timer",
+                Role::ToolBar => "toolbar",
+                Role::ToolTip => "tooltip",
+                Role::Tree => "tree",
+                Role::TreeGrid => "treegrid",
+                Role::TreeItem => "treeitem",
+            }
+        }
+    }
+
+    impl AttributeValue for Role {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(self.as_str().as_bytes())
+        }
+    }
+
+
+    //--- Sandbox
+
+    /// A restriction lifted for the content of a sandboxed iframe.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Sandbox {
+        AllowDownloads,
+        AllowForms,
+        AllowModals,
+        AllowOrientationLock,
+        AllowPointerLock,
+        AllowPopups,
+        AllowPopupsToEscapeSandbox,
+        AllowPresentation,
+        AllowSameOrigin,
+        AllowScripts,
+        AllowTopNavigation,
+        AllowTopNavigationByUserActivation,
+        AllowTopNavigationToCustomProtocols,
+    }
+
+    impl Sandbox {
+        pub fn as_str(self) -> &'static str {
+            match self {
+                Sandbox::AllowDownloads => "allow-downloads",
+                Sandbox::AllowForms => "allow-forms",
+                Sandbox::AllowModals => "allow-modals",
+                Sandbox::AllowOrientationLock => "allow-orientation-lock",
+                Sandbox::AllowPointerLock => "allow-pointer-lock",
+                Sandbox::AllowPopups => "allow-popups",
+                Sandbox::AllowPopupsToEscapeSandbox => {
+                    "allow-popups-to-escape-sandbox"
+                }
+                Sandbox::AllowPresentation => "allow-presentation",
+                Sandbox::AllowSameOrigin => "allow-same-origin",
+                Sandbox::AllowScripts => "allow-scripts",
+                Sandbox::AllowTopNavigation => "allow-top-navigation",
+                Sandbox::AllowTopNavigationByUserActivation => {
+                    "allow-top-navigation-by-user-activation"
+                }
+                Sandbox::AllowTopNavigationToCustomProtocols => {
+                    "allow-top-navigation-to-custom-protocols"
+                }
+            }
+        }
+    }
+
+    struct SandboxTokens<I>(I);
+
+    impl<I: Iterator<Item = Sandbox>> AttributeValue for SandboxTokens<I> {
+        fn render_attr_value(self, target: &mut Target) {
+            for (idx, item) in self.0.enumerate() {
+                if idx > 0 {
+                    target.append_slice(b" ");
+                }
+                target.append_slice(item.as_str().as_bytes());
+            }
+        }
+    }
+
+
+    //--- ShadowRootMode
+
+    /// Whether a shadow root is accessible from outside of its host.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ShadowRootMode {
+        Open,
+        Closed,
+    }
+
+    impl AttributeValue for ShadowRootMode {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                ShadowRootMode::Open => b"open",
+                ShadowRootMode::Closed => b"closed",
+            })
+        }
+    }
+
+
+    //--- Shape
+
+    /// The shape of an image map area.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Shape {
+        Circle,
+        Default,
+        Poly,
+        Rect,
+    }
+
+    impl AttributeValue for Shape {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Shape::Circle => b"circle",
+                Shape::Default => b"default",
+                Shape::Poly => b"poly",
+                Shape::Rect => b"rect",
+            })
+        }
+    }
+
+
+    //--- Sizes
+
+    struct Sizes<I>(I, Length);
+
+    impl<I, M> AttributeValue for Sizes<I>
+    where I: Iterator<Item = (M, Length)>, M: AsRef<str> {
+        fn render_attr_value(self, target: &mut Target) {
+            for (condition, length) in self.0 {
+                escape::render_attr(condition.as_ref(), target);
+                target.append_slice(b" ");
+                length.render_attr_value(target);
+                target.append_slice(b", ");
+            }
+            self.1.render_attr_value(target)
+        }
+    }
+
+
+    //--- Srcset
+
+    struct Srcset<I>(I);
+
+    impl<I, U> AttributeValue for Srcset<I>
+    where I: Iterator<Item = (U, Descriptor)>, U: AsRef<str> {
+        fn render_attr_value(self, target: &mut Target) {
+            let mut first = true;
+            for (url, descriptor) in self.0 {
+                if first {
+                    first = false;
+                }
+                else {
+                    target.append_slice(b", ");
+                }
+                render_srcset_url(url.as_ref(), target);
+                target.append_slice(b" ");
+                descriptor.render_attr_value(target);
+            }
+        }
+    }
+
+    fn render_srcset_url(url: &str, target: &mut Target) {
+        let len = url.len();
+        let needs_encoding = url.starts_with(',') || url.ends_with(',')
+            || url.contains(|ch: char| ch.is_ascii_whitespace());
+        if !needs_encoding {
+            return escape::render_attr(url, target)
+        }
+        let mut encoded = String::with_capacity(len + 6);
+        for (idx, ch) in url.char_indices() {
+            if ch.is_ascii_whitespace()
+                || (ch == ',' && (idx == 0 || idx + 1 == len))
+            {
+                encoded.push_str(&format!("%{:02X}", ch as u32));
+            }
+            else {
+                encoded.push(ch)
+            }
+        }
+        escape::render_attr(&encoded, target)
+    }
+
+
+    //--- TrackKind
+
+    /// The kind of a text track.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum TrackKind {
+        Subtitles,
+        Captions,
+        Descriptions,
+        Chapters,
+        Metadata,
+    }
+
+    impl AttributeValue for TrackKind {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                TrackKind::Subtitles => b"subtitles",
+                TrackKind::Captions => b"captions",
+                TrackKind::Descriptions => b"descriptions",
+                TrackKind::Chapters => b"chapters",
+                TrackKind::Metadata => b"metadata",
+            })
+        }
     }
 
 
@@ -369,3 +4313,306 @@ pub mod attr {
 
 }
 
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::Target;
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    fn render_attrs(attrs: impl Attributes) -> String {
+        let mut target = Target::new();
+        attrs.render_attrs(&mut target);
+        String::from_utf8(target.as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn strict_elements() {
+        assert_eq!(
+            render(strict::ul((
+                strict::li(strict::p(("a", strict::br(), strict::em("b")))),
+                strict::li(strict::input::text("q", ())),
+            ))),
+            "<ul><li><p>a<br/><em>b</em></p></li>\
+             <li><input name=\"q\" type=\"text\"/></li></ul>"
+        );
+    }
+
+    #[test]
+    fn strict_wrappers() {
+        assert_eq!(
+            render(strict::details(
+                strict::summary("more"), strict::p("text")
+            )),
+            "<details><summary>more</summary><p>text</p></details>"
+        );
+        assert_eq!(
+            render(strict::fieldset::legend(
+                strict::legend("a"), strict::input::text("q", ())
+            )),
+            "<fieldset><legend>a</legend>\
+             <input name=\"q\" type=\"text\"/></fieldset>"
+        );
+        assert_eq!(
+            render(strict::figure::caption(
+                strict::figcaption("c"), strict::img("x.png", "x")
+            )),
+            "<figure><figcaption>c</figcaption>\
+             <img src=\"x.png\" alt=\"x\"/></figure>"
+        );
+        assert_eq!(
+            render(strict::select("s", (
+                strict::option("a", true, "A"),
+                strict::optgroup("g", strict::option("b", false, "B")),
+            ))),
+            "<select name=\"s\"><option value=\"a\" selected>A</option>\
+             <optgroup label=\"g\"><option value=\"b\">B</option>\
+             </optgroup></select>"
+        );
+        assert_eq!(
+            render(strict::p(strict::video(
+                strict::source::src("a.webm", "video/webm"), "no video"
+            ))),
+            "<p><video><source src=\"a.webm\" type=\"video/webm\"/>\
+             no video</video></p>"
+        );
+        assert_eq!(
+            render(strict::p(PhrasingContent::unchecked(
+                raw("<x-y></x-y>")
+            ))),
+            "<p><x-y></x-y></p>"
+        );
+    }
+
+    #[test]
+    fn inline_script() {
+        assert_eq!(
+            render(script::inline("a('</Script>', 1 < 2)")),
+            "<script>a('<\\/Script>', 1 < 2)</script>"
+        );
+        assert_eq!(
+            render(script::inline_attrs(
+                attr::type_("module"), "/* <!-- */"
+            )),
+            "<script type=\"module\">/* <\\!-- */</script>"
+        );
+    }
+
+    #[test]
+    fn js_values() {
+        use attr::{Js, JsValue};
+
+        assert_eq!(
+            render_attrs(attr::onclick(Js::call("f", [
+                JsValue::String("say \"hi\"\\n"),
+                JsValue::String("a\nb"),
+                JsValue::String("</script>"),
+            ]))),
+            " onclick=\"f(&quot;say \\&quot;hi\\&quot;\\\\n&quot;, \
+             &quot;a\\nb&quot;, &quot;\\u003c/script\\u003e&quot;)\""
+        );
+        assert_eq!(
+            render_attrs(attr::onclick(Js::call("g", [
+                JsValue::Number(f64::NAN),
+                JsValue::Number(f64::INFINITY),
+                JsValue::Number(f64::NEG_INFINITY),
+                JsValue::Number(1.5),
+                JsValue::Null,
+                true.into(),
+            ]))),
+            " onclick=\"g(NaN, Infinity, -Infinity, 1.5, null, true)\""
+        );
+        assert_eq!(
+            render_attrs(attr::onclick(Js::call("f", ["it's".into()]))),
+            " onclick=\"f(&quot;it\\u0027s&quot;)\""
+        );
+    }
+
+    #[test]
+    fn event_handlers() {
+        assert_eq!(
+            render_attrs(attr::on("pointerdown", attr::Js::new("f()"))),
+            " onpointerdown=\"f()\""
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_event_handler() {
+        let _ = attr::on("clik", attr::Js::new("f()"));
+    }
+
+    #[test]
+    fn sizes() {
+        use attr::Length;
+
+        assert_eq!(
+            render_attrs(attr::sizes(
+                [("(max-width: 600px)", Length::Vw(100.))], Length::Px(480)
+            )),
+            " sizes=\"(max-width: 600px) 100vw, 480px\""
+        );
+        assert_eq!(
+            render_attrs(attr::sizes(
+                Vec::<(&str, Length)>::new(), Length::Em(2.5)
+            )),
+            " sizes=\"2.5em\""
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "comma in media condition")]
+    fn sizes_with_comma() {
+        let _ = attr::sizes(
+            [("(min-width: 1px), print", attr::Length::Px(1))],
+            attr::Length::Px(2)
+        );
+    }
+
+    #[test]
+    fn srcset() {
+        use attr::Descriptor;
+
+        assert_eq!(
+            render_attrs(attr::srcset([
+                ("a.png", Descriptor::Width(480)),
+                ("b.png", Descriptor::Density(1.5)),
+            ])),
+            " srcset=\"a.png 480w, b.png 1.5x\""
+        );
+        assert_eq!(
+            render_attrs(attr::srcset([
+                ("my image.png", Descriptor::Width(1)),
+                (",a,b.png,", Descriptor::Width(2)),
+                ("a&b.png", Descriptor::Width(3)),
+            ])),
+            " srcset=\"my%20image.png 1w, %2Ca,b.png%2C 2w, a&amp;b.png 3w\""
+        );
+    }
+
+    #[test]
+    fn numeric_elements() {
+        assert_eq!(
+            render(meter(0.5, 0., 1., "half")),
+            "<meter value=\"0.5\" min=\"0\" max=\"1\">half</meter>"
+        );
+        assert_eq!(
+            render(progress(3., 10., ())),
+            "<progress value=\"3\" max=\"10\"></progress>"
+        );
+        assert_eq!(
+            render(input::number("n", Some(-1.5), None, Some(0.5), ())),
+            "<input name=\"n\" min=\"-1.5\" step=\"0.5\" \
+             type=\"number\"/>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-finite number")]
+    fn meter_nan() {
+        let _ = meter(f64::NAN, 0., 1., ());
+    }
+
+    #[test]
+    #[should_panic(expected = "non-finite number")]
+    fn progress_infinite() {
+        let _ = progress(1., f64::INFINITY, ());
+    }
+
+    #[test]
+    #[should_panic(expected = "non-finite number")]
+    fn number_infinite() {
+        let _ = input::number("n", None, Some(f64::NEG_INFINITY), None, ());
+    }
+
+    #[test]
+    fn list_helpers() {
+        assert_eq!(
+            render(ul::items(["a", "b"], |item| item)),
+            "<ul><li>a</li><li>b</li></ul>"
+        );
+        assert_eq!(
+            render(ol::items(1..3, |num| em(display(num)))),
+            "<ol><li><em>1</em></li><li><em>2</em></li></ol>"
+        );
+        assert_eq!(render(ul::items(Vec::<&str>::new(), |x| x)), "<ul></ul>");
+        assert_eq!(
+            render(dl::pairs([("a", "x"), ("b", "y")])),
+            "<dl><dt>a</dt><dd>x</dd><dt>b</dt><dd>y</dd></dl>"
+        );
+        assert_eq!(
+            render(ol::start(-2, li("a"))),
+            "<ol start=\"-2\"><li>a</li></ol>"
+        );
+        assert_eq!(
+            render(ol::attrs(attr::type_(attr::ListType::LowerRoman), ())),
+            "<ol type=\"i\"></ol>"
+        );
+    }
+
+    #[test]
+    fn typed_input_keeps_type() {
+        assert_eq!(
+            render(input::checkbox("a", "1", true, attr::type_("text"))),
+            "<input name=\"a\" value=\"1\" checked type=\"checkbox\"/>"
+        );
+        assert_eq!(
+            render(input::text("q", attr::id("q"))),
+            "<input name=\"q\" id=\"q\" type=\"text\"/>"
+        );
+    }
+
+    #[test]
+    fn datetime() {
+        use attr::{Date, Datetime, Time};
+        use std::time::Duration;
+
+        let date = Date::new(2024, 3, 9);
+        assert_eq!(
+            render(time::datetime(Datetime::Date(date), "today")),
+            "<time datetime=\"2024-03-09\">today</time>"
+        );
+        assert_eq!(
+            render(strict::del::datetime(
+                Datetime::Global(date, Time::new(8, 5, 0), -90), "x"
+            )),
+            "<del datetime=\"2024-03-09T08:05-01:30\">x</del>"
+        );
+        assert_eq!(
+            render_attrs(attr::datetime(Datetime::Global(
+                date, Time::new(8, 5, 7), 0
+            ))),
+            " datetime=\"2024-03-09T08:05:07Z\""
+        );
+        assert_eq!(
+            render_attrs(attr::datetime(Datetime::Month(2024, 3))),
+            " datetime=\"2024-03\""
+        );
+        assert_eq!(
+            render_attrs(attr::datetime(
+                Datetime::Duration(Duration::from_millis(1500))
+            )),
+            " datetime=\"PT1.500S\""
+        );
+    }
+
+    #[test]
+    fn empty_element_attrs() {
+        assert_eq!(render(br::class("a")), "<br class=\"a\"/>");
+        assert_eq!(render(strict::wbr::id("b")), "<wbr id=\"b\"/>");
+        assert_eq!(
+            render(strict::figure::caption_last(
+                strict::img("a.png", "A"),
+                strict::figcaption(strict::p("A")),
+            )),
+            "<figure><img src=\"a.png\" alt=\"A\"/>\
+             <figcaption><p>A</p></figcaption></figure>"
+        );
+    }
+}
@@ -0,0 +1,425 @@
+//! Page layouts with named slots.
+//!
+//! A [`Layout`] renders the parts of a page shared by many pages, such as
+//! the document skeleton, navigation, and footer, and places the content
+//! of a page into named slots. Pages fill these slots via [`Slots`] and
+//! then render the layout with them.
+//!
+//! The constants in this module name the slots used by the [`Document`]
+//! layout. Other layouts are free to use their own slot names. A slot name
+//! also determines what kind of content the slot takes: any content, only
+//! flow content, or only text. A slot taken from [`Slots`] belongs to the
+//! same content category as its content, so it can be used wherever that
+//! content could be. Using the same name with different kinds is an error.
+//!
+//! Layouts can be nested: a layout can take some of the slots it is given,
+//! wrap their content in its own elements, and fill them into the slots of
+//! an outer layout.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+use crate::core::{Content, Flow, Target, Text, TextElement};
+use crate::html::{self, aside, footer, header, main, meta};
+use crate::utils::Either;
+
+
+//------------ Slot Names ----------------------------------------------------
+
+/// The title of the page.
+pub const TITLE: SlotName<TextSlot> = SlotName::new("title");
+
+/// Additional elements for the document’s `head`.
+pub const HEAD: SlotName<AnySlot> = SlotName::new("head");
+
+/// The banner at the top of the page, usually containing the navigation.
+pub const HEADER: SlotName<AnySlot> = SlotName::new("header");
+
+/// The main content of the page.
+pub const MAIN: SlotName<AnySlot> = SlotName::new("main");
+
+/// Content related to the main content shown beside it.
+pub const SIDEBAR: SlotName<AnySlot> = SlotName::new("sidebar");
+
+/// The footer at the bottom of the page.
+pub const FOOTER: SlotName<AnySlot> = SlotName::new("footer");
+
+/// Scripts loaded at the end of the document’s `body`.
+pub const SCRIPTS: SlotName<AnySlot> = SlotName::new("scripts");
+
+
+//------------ SlotName ------------------------------------------------------
+
+/// The name of a slot and the kind `K` of content it takes.
+///
+/// Slots are identified by their name alone. All uses of a name must
+/// therefore agree on the kind.
+pub struct SlotName<K> {
+    name: &'static str,
+    marker: PhantomData<K>,
+}
+
+impl<K> SlotName<K> {
+    pub const fn new(name: &'static str) -> Self {
+        SlotName { name, marker: PhantomData }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        self.name
+    }
+}
+
+impl<K> Clone for SlotName<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for SlotName<K> { }
+
+
+//------------ Slot Kinds ----------------------------------------------------
+
+/// A slot taking any content.
+pub enum AnySlot { }
+
+/// A slot taking flow content.
+pub enum FlowSlot { }
+
+/// A slot taking text.
+pub enum TextSlot { }
+
+/// Content that can fill a slot of kind `K`.
+pub trait Fill<K>: Content { }
+
+impl<C: Content> Fill<AnySlot> for C { }
+
+impl<C: Flow> Fill<FlowSlot> for C { }
+
+impl<C: Text> Fill<TextSlot> for C { }
+
+
+//------------ Layout --------------------------------------------------------
+
+/// A type that renders the content of slots into a page.
+pub trait Layout: Sized {
+    /// Renders the layout with the given slots.
+    fn render_layout(self, slots: Slots<'_>, target: &mut Target);
+
+    /// Returns the layout with the given slots as content.
+    fn page(self, slots: Slots<'_>) -> Page<'_, Self> {
+        Page { layout: self, slots }
+    }
+}
+
+
+//------------ Page ----------------------------------------------------------
+
+/// A layout with the slots filled by a page.
+pub struct Page<'a, L> {
+    layout: L,
+    slots: Slots<'a>,
+}
+
+impl<L: Layout> Content for Page<'_, L> {
+    fn render_content(self, target: &mut Target) {
+        self.layout.render_layout(self.slots, target)
+    }
+}
+
+
+//------------ Slots ---------------------------------------------------------
+
+/// The content of the named slots of a layout.
+///
+/// The content is only rendered when the layout takes a slot and renders
+/// it. Slots that are never taken are not rendered at all.
+///
+/// # Panics
+///
+/// Filling or taking a slot panics if the slot has been filled before
+/// under a name of a different kind. Otherwise, the content would
+/// either end up where its category isn't allowed or be silently dropped.
+#[derive(Default)]
+pub struct Slots<'a> {
+    slots: Vec<(&'static str, TypeId, Vec<Part<'a>>)>,
+}
+
+impl<'a> Slots<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds content to a slot.
+    ///
+    /// If the slot already has content, the new content is added after it.
+    pub fn fill<K: 'static>(
+        mut self, name: SlotName<K>, content: impl Fill<K> + 'a
+    ) -> Self {
+        self.parts_mut(name).push(part(content));
+        self
+    }
+
+    /// Replaces the content of a slot.
+    pub fn replace<K: 'static>(
+        mut self, name: SlotName<K>, content: impl Fill<K> + 'a
+    ) -> Self {
+        let parts = self.parts_mut(name);
+        parts.clear();
+        parts.push(part(content));
+        self
+    }
+
+    /// Returns whether a slot has content.
+    pub fn is_filled<K>(&self, name: SlotName<K>) -> bool {
+        self.slots.iter().any(|(item, _, parts)| {
+            *item == name.name && !parts.is_empty()
+        })
+    }
+
+    /// Takes the content of a slot.
+    ///
+    /// The returned slot is empty if the slot was never filled or has been
+    /// taken before.
+    pub fn take<K: 'static>(&mut self, name: SlotName<K>) -> Slot<'a, K> {
+        let parts = match self.position(name) {
+            Some(idx) => self.slots.remove(idx).2,
+            None => Vec::new(),
+        };
+        Slot { parts, marker: PhantomData }
+    }
+
+    fn parts_mut<K: 'static>(
+        &mut self, name: SlotName<K>
+    ) -> &mut Vec<Part<'a>> {
+        let idx = match self.position(name) {
+            Some(idx) => idx,
+            None => {
+                self.slots.push((name.name, TypeId::of::<K>(), Vec::new()));
+                self.slots.len() - 1
+            }
+        };
+        &mut self.slots[idx].2
+    }
+
+    /// Returns the index of a slot, checking that its kind matches.
+    fn position<K: 'static>(&self, name: SlotName<K>) -> Option<usize> {
+        let idx = self.slots.iter().position(|(item, _, _)| {
+            *item == name.name
+        })?;
+        assert!(
+            self.slots[idx].1 == TypeId::of::<K>(),
+            "slot '{}' used with different kinds", name.name
+        );
+        Some(idx)
+    }
+}
+
+
+//------------ Slot ----------------------------------------------------------
+
+/// The content of a single slot of kind `K`.
+///
+/// The slot belongs to the content category of its kind: a slot of
+/// [`FlowSlot`] kind is [`Flow`] and one of [`TextSlot`] kind is [`Text`].
+pub struct Slot<'a, K = AnySlot> {
+    parts: Vec<Part<'a>>,
+    marker: PhantomData<K>,
+}
+
+/// A piece of content added to a slot, rendered when the slot is.
+type Part<'a> = Box<dyn FnOnce(&mut Target) + 'a>;
+
+fn part<'a>(content: impl Content + 'a) -> Part<'a> {
+    Box::new(|target| content.render_content(target))
+}
+
+impl<K> Default for Slot<'_, K> {
+    fn default() -> Self {
+        Slot { parts: Vec::new(), marker: PhantomData }
+    }
+}
+
+impl<K> Slot<'_, K> {
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Returns the slot or the given default content if it is empty.
+    pub fn or<C: Content>(self, default: C) -> Either<Self, C> {
+        if self.is_empty() {
+            Either::Right(default)
+        }
+        else {
+            Either::Left(self)
+        }
+    }
+
+    /// Returns the slot wrapped by `op` or nothing if it is empty.
+    pub fn map<C: Content>(self, op: impl FnOnce(Self) -> C) -> Option<C> {
+        (!self.is_empty()).then(|| op(self))
+    }
+}
+
+impl<K> Content for Slot<'_, K> {
+    fn render_content(self, target: &mut Target) {
+        for part in self.parts {
+            part(target)
+        }
+    }
+}
+
+impl Flow for Slot<'_, FlowSlot> { }
+
+impl Text for Slot<'_, TextSlot> { }
+
+
+//------------ Document ------------------------------------------------------
+
+/// A layout for a complete HTML document.
+///
+/// The layout renders the doctype, a `head` with the character set,
+/// viewport, title, and the content of the [`HEAD`] slot, and a `body`.
+/// The body contains the [`HEADER`], [`MAIN`], [`SIDEBAR`], and [`FOOTER`]
+/// slots wrapped in `header`, `main`, `aside`, and `footer` elements,
+/// followed by the [`SCRIPTS`] slot. The elements for empty slots other
+/// than the main slot are left out.
+#[derive(Clone, Debug)]
+pub struct Document<'a> {
+    lang: &'a str,
+    title: &'a str,
+}
+
+impl<'a> Document<'a> {
+    /// Creates a document layout for the given language.
+    pub fn new(lang: &'a str) -> Self {
+        Document { lang, title: "" }
+    }
+
+    /// Sets the title used if the title slot is empty.
+    pub fn default_title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+}
+
+impl Layout for Document<'_> {
+    fn render_layout(self, mut slots: Slots<'_>, target: &mut Target) {
+        (
+            html::doctype(),
+            html::html(
+                self.lang,
+                html::head((
+                    meta::charset("utf-8"),
+                    meta::name(
+                        "viewport", "width=device-width, initial-scale=1"
+                    ),
+                    TextElement::new(
+                        "title", (), slots.take(TITLE).or(self.title)
+                    ),
+                    slots.take(HEAD),
+                )),
+                html::body((
+                    slots.take(HEADER).map(header),
+                    main(slots.take(MAIN)),
+                    slots.take(SIDEBAR).map(aside),
+                    slots.take(FOOTER).map(footer),
+                    slots.take(SCRIPTS),
+                ))
+            )
+        ).render_content(target)
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::{p, strict};
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn document() {
+        let slots = Slots::new()
+            .fill(TITLE, "a < b")
+            .fill(MAIN, p("x"))
+            .fill(MAIN, p("y"))
+            .fill(FOOTER, "f")
+            .replace(FOOTER, "g");
+        assert!(slots.is_filled(MAIN));
+        assert!(!slots.is_filled(SIDEBAR));
+        assert_eq!(
+            render(Document::new("en").page(slots)),
+            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\"/>\
+             <meta name=\"viewport\" \
+             content=\"width=device-width, initial-scale=1\"/>\
+             <title>a &lt; b</title></head>\
+             <body><main><p>x</p><p>y</p></main><footer>g</footer>\
+             </body></html>"
+        );
+    }
+
+    #[test]
+    fn slot_categories() {
+        const NOTE: SlotName<FlowSlot> = SlotName::new("note");
+        const OTHER: SlotName<AnySlot> = SlotName::new("other");
+
+        let mut slots = Slots::new()
+            .fill(NOTE, strict::p("a"))
+            .fill(OTHER, "b");
+        assert_eq!(
+            render(strict::div(slots.take(NOTE))), "<div><p>a</p></div>"
+        );
+        assert!(slots.take(NOTE).is_empty());
+        assert_eq!(render(slots.take(OTHER).or("c")), "b");
+        assert_eq!(render(slots.take(OTHER).or("c")), "c");
+    }
+
+    #[test]
+    #[should_panic(expected = "different kinds")]
+    fn slot_kind_mismatch() {
+        const TITLE_ANY: SlotName<AnySlot> = SlotName::new("title");
+
+        let _ = render(
+            Document::new("en").page(Slots::new().fill(TITLE_ANY, "a"))
+        );
+    }
+
+    /// A layout wrapping an article into the main slot of a document.
+    struct Article;
+
+    /// The body of the article.
+    const BODY: SlotName<FlowSlot> = SlotName::new("body");
+
+    impl Layout for Article {
+        fn render_layout(self, mut slots: Slots<'_>, target: &mut Target) {
+            let title = slots.take(TITLE);
+            let body = slots.take(BODY);
+            let slots = slots
+                .replace(TITLE, (title, " – Site"))
+                .fill(MAIN, strict::article(body));
+            Document::new("en").render_layout(slots, target)
+        }
+    }
+
+    #[test]
+    fn nested_layout() {
+        let slots = Slots::new()
+            .fill(TITLE, "News")
+            .fill(BODY, strict::p("x"))
+            .fill(FOOTER, "f");
+        assert_eq!(
+            render(Article.page(slots)),
+            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\"/>\
+             <meta name=\"viewport\" \
+             content=\"width=device-width, initial-scale=1\"/>\
+             <title>News – Site</title></head>\
+             <body><main><article><p>x</p></article></main>\
+             <footer>f</footer></body></html>"
+        );
+    }
+}
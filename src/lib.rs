@@ -1,6 +1,17 @@
 mod escape;
+mod parse;
 
+pub mod a11y;
+pub mod chart;
+pub mod component;
+pub mod check;
 pub mod core;
+pub mod form;
 pub mod html;
+pub mod layout;
+pub mod mathml;
+pub mod schema;
+pub mod seo;
+pub mod svg;
 pub mod utils;
 
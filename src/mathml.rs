@@ -0,0 +1,799 @@
+//! MathML.
+//!
+//! The elements in this module are meant to be embedded in HTML. Elements
+//! with a fixed number of children such as [`mfrac`] take each child as a
+//! separate argument. Each of these children should be a single element,
+//! use [`mrow`] to group several.
+//!
+//! The [`tex`] function converts a small subset of LaTeX into MathML.
+
+use std::{error, fmt};
+use crate::core::{
+    Attributes, AttributeValue, Content, Element, EmptyElement, Target,
+};
+use crate::utils::iter;
+
+//------------ Elements ------------------------------------------------------
+
+macro_rules! element {
+    ( $name:ident ) => {
+        pub fn $name(content: impl Content) -> impl Content {
+            Element::new(stringify!($name), (), content)
+        }
+
+        pub mod $name {
+            use super::*;
+
+            pub fn attrs(
+                attrs: impl Attributes, content: impl Content
+            ) -> impl Content {
+                Element::new(stringify!($name), attrs, content)
+            }
+        }
+    }
+}
+
+macro_rules! pair {
+    ( $name:ident, $first:ident, $second:ident ) => {
+        pub fn $name(
+            $first: impl Content, $second: impl Content
+        ) -> impl Content {
+            Element::new(stringify!($name), (), ($first, $second))
+        }
+
+        pub mod $name {
+            use super::*;
+
+            pub fn attrs(
+                attrs: impl Attributes,
+                $first: impl Content,
+                $second: impl Content,
+            ) -> impl Content {
+                Element::new(stringify!($name), attrs, ($first, $second))
+            }
+        }
+    }
+}
+
+macro_rules! triple {
+    ( $name:ident, $first:ident, $second:ident, $third:ident ) => {
+        pub fn $name(
+            $first: impl Content, $second: impl Content, $third: impl Content
+        ) -> impl Content {
+            Element::new(stringify!($name), (), ($first, $second, $third))
+        }
+
+        pub mod $name {
+            use super::*;
+
+            pub fn attrs(
+                attrs: impl Attributes,
+                $first: impl Content,
+                $second: impl Content,
+                $third: impl Content,
+            ) -> impl Content {
+                Element::new(
+                    stringify!($name), attrs, ($first, $second, $third)
+                )
+            }
+        }
+    }
+}
+
+//--- math
+
+/// Creates the root element of a formula rendered inline.
+pub fn math(content: impl Content) -> impl Content {
+    Element::new("math", (), content)
+}
+
+pub mod math {
+    use super::*;
+
+    pub fn attrs(
+        attrs: impl Attributes, content: impl Content
+    ) -> impl Content {
+        Element::new("math", attrs, content)
+    }
+
+    /// Creates a formula rendered as a block.
+    pub fn block(content: impl Content) -> impl Content {
+        Element::new(
+            "math", attr::display(attr::Display::Block), content
+        )
+    }
+}
+
+//--- annotation
+
+pub fn annotation(
+    encoding: impl AttributeValue, content: impl Content
+) -> impl Content {
+    Element::new("annotation", attr::encoding(encoding), content)
+}
+
+//--- merror
+
+element!(merror);
+
+//--- mfrac
+
+pair!(mfrac, numerator, denominator);
+
+//--- mi, mn, mo, ms, mtext
+
+element!(mi);
+element!(mn);
+element!(mo);
+element!(ms);
+element!(mtext);
+
+//--- mmultiscripts, mprescripts
+
+element!(mmultiscripts);
+
+pub fn mprescripts() -> impl Content {
+    EmptyElement::new("mprescripts", ())
+}
+
+//--- mover, munder, munderover
+
+pair!(mover, base, overscript);
+pair!(munder, base, underscript);
+triple!(munderover, base, underscript, overscript);
+
+//--- mpadded, mphantom
+
+element!(mpadded);
+element!(mphantom);
+
+//--- mroot, msqrt
+
+pair!(mroot, base, index);
+element!(msqrt);
+
+//--- mrow
+
+element!(mrow);
+
+//--- mspace
+
+pub fn mspace(attrs: impl Attributes) -> impl Content {
+    EmptyElement::new("mspace", attrs)
+}
+
+//--- mstyle
+
+element!(mstyle);
+
+//--- msub, msup, msubsup
+
+pair!(msub, base, subscript);
+pair!(msup, base, superscript);
+triple!(msubsup, base, subscript, superscript);
+
+//--- mtable, mtr, mtd
+
+element!(mtable);
+element!(mtd);
+element!(mtr);
+
+//--- semantics
+
+element!(semantics);
+
+
+//------------ Attributes ----------------------------------------------------
+
+pub mod attr {
+    use crate::core::{Attr, Attributes, AttributeValue, Target};
+
+    pub use crate::html::attr::{class, data, id, style};
+
+    pub fn accent(value: bool) -> impl Attributes {
+        Attr::new("accent", if value { "true" } else { "false" })
+    }
+
+    pub fn columnspan(value: u64) -> impl Attributes {
+        Attr::new("columnspan", crate::utils::display(value))
+    }
+
+    pub fn depth(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("depth", value)
+    }
+
+    pub fn display(value: Display) -> impl Attributes {
+        Attr::new("display", value)
+    }
+
+    pub fn displaystyle(value: bool) -> impl Attributes {
+        Attr::new("displaystyle", if value { "true" } else { "false" })
+    }
+
+    pub fn encoding(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("encoding", value)
+    }
+
+    pub fn fence(value: bool) -> impl Attributes {
+        Attr::new("fence", if value { "true" } else { "false" })
+    }
+
+    pub fn form(value: Form) -> impl Attributes {
+        Attr::new("form", value)
+    }
+
+    pub fn height(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("height", value)
+    }
+
+    pub fn largeop(value: bool) -> impl Attributes {
+        Attr::new("largeop", if value { "true" } else { "false" })
+    }
+
+    pub fn linethickness(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("linethickness", value)
+    }
+
+    pub fn lspace(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("lspace", value)
+    }
+
+    pub fn mathvariant(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("mathvariant", value)
+    }
+
+    pub fn movablelimits(value: bool) -> impl Attributes {
+        Attr::new("movablelimits", if value { "true" } else { "false" })
+    }
+
+    pub fn rowspan(value: u64) -> impl Attributes {
+        Attr::new("rowspan", crate::utils::display(value))
+    }
+
+    pub fn rspace(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("rspace", value)
+    }
+
+    pub fn scriptlevel(value: i64) -> impl Attributes {
+        Attr::new("scriptlevel", crate::utils::display(value))
+    }
+
+    pub fn separator(value: bool) -> impl Attributes {
+        Attr::new("separator", if value { "true" } else { "false" })
+    }
+
+    pub fn stretchy(value: bool) -> impl Attributes {
+        Attr::new("stretchy", if value { "true" } else { "false" })
+    }
+
+    pub fn symmetric(value: bool) -> impl Attributes {
+        Attr::new("symmetric", if value { "true" } else { "false" })
+    }
+
+    pub fn width(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("width", value)
+    }
+
+
+    //--- Display
+
+    /// How a `math` element is laid out.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Display {
+        Block,
+        Inline,
+    }
+
+    impl AttributeValue for Display {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Display::Block => b"block",
+                Display::Inline => b"inline",
+            })
+        }
+    }
+
+
+    //--- Form
+
+    /// The position of an operator within its row.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Form {
+        Prefix,
+        Infix,
+        Postfix,
+    }
+
+    impl AttributeValue for Form {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Form::Prefix => b"prefix",
+                Form::Infix => b"infix",
+                Form::Postfix => b"postfix",
+            })
+        }
+    }
+}
+
+
+//------------ tex -----------------------------------------------------------
+
+/// Converts a LaTeX-like expression into MathML.
+///
+/// Only a small subset of LaTeX math is supported: letters, numbers and
+/// operators, groups in braces, sub- and superscripts via `_` and `^`,
+/// `\frac`, `\sqrt` with an optional index, `\text`, the spacing commands
+/// `\,`, `\;` and `\quad`, Greek letters, common function names such as
+/// `\sin` and a selection of symbols.
+///
+/// Groups, arguments, and scripts can be nested at most [`TEX_MAX_DEPTH`]
+/// levels deep.
+///
+/// The result is a row of elements and needs to be placed inside a
+/// [`math`] element.
+pub fn tex(expr: &str) -> Result<Tex, TexError> {
+    let mut parser = TexParser { src: expr, pos: 0, depth: 0 };
+    let nodes = parser.row(None)?;
+    Ok(Tex(Node::Row(nodes)))
+}
+
+/// The maximum nesting depth of an expression accepted by [`tex`].
+pub const TEX_MAX_DEPTH: usize = 64;
+
+
+//------------ Tex -----------------------------------------------------------
+
+/// A formula converted from LaTeX.
+#[derive(Clone, Debug)]
+pub struct Tex(Node);
+
+impl Content for Tex {
+    fn render_content(self, target: &mut Target) {
+        self.0.render_content(target)
+    }
+}
+
+
+//------------ TexError ------------------------------------------------------
+
+/// An error happened while converting LaTeX.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TexError {
+    /// The byte offset in the expression where the error was found.
+    pub offset: usize,
+
+    /// What went wrong.
+    pub kind: TexErrorKind,
+}
+
+/// The kind of a [`TexError`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TexErrorKind {
+    /// The expression ended while an argument or group was expected.
+    UnexpectedEnd,
+
+    /// A closing brace without a matching opening brace.
+    UnmatchedBrace,
+
+    /// A sub- or superscript without a base.
+    MissingBase,
+
+    /// A second subscript or superscript for the same base.
+    DoubleScript,
+
+    /// A command that isn’t supported.
+    UnknownCommand(String),
+
+    /// The expression is nested too deeply.
+    TooDeep,
+}
+
+impl fmt::Display for TexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+impl fmt::Display for TexErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TexErrorKind::UnexpectedEnd => f.write_str("unexpected end"),
+            TexErrorKind::UnmatchedBrace => f.write_str("unmatched brace"),
+            TexErrorKind::MissingBase => {
+                f.write_str("script without a base")
+            }
+            TexErrorKind::DoubleScript => f.write_str("double script"),
+            TexErrorKind::UnknownCommand(name) => {
+                write!(f, "unknown command \\{}", name)
+            }
+            TexErrorKind::TooDeep => f.write_str("nested too deeply"),
+        }
+    }
+}
+
+impl error::Error for TexError { }
+
+
+//------------ Node ----------------------------------------------------------
+
+/// A node of a formula converted from LaTeX.
+#[derive(Clone, Debug)]
+enum Node {
+    Ident(String),
+    Number(String),
+    Op(String),
+    Text(String),
+    Space(&'static str),
+    Row(Vec<Node>),
+    Frac(Box<Node>, Box<Node>),
+    Sqrt(Box<Node>),
+    Root(Box<Node>, Box<Node>),
+    Sub(Box<Node>, Box<Node>),
+    Sup(Box<Node>, Box<Node>),
+    SubSup(Box<Node>, Box<Node>, Box<Node>),
+}
+
+impl Content for Node {
+    fn render_content(self, target: &mut Target) {
+        match self {
+            Node::Ident(name) => mi(name).render_content(target),
+            Node::Number(value) => mn(value).render_content(target),
+            Node::Op(op) => mo(op).render_content(target),
+            Node::Text(text) => mtext(text).render_content(target),
+            Node::Space(width) => {
+                mspace(attr::width(width)).render_content(target)
+            }
+            Node::Row(nodes) => {
+                mrow(iter(nodes.into_iter())).render_content(target)
+            }
+            Node::Frac(num, den) => {
+                mfrac(*num, *den).render_content(target)
+            }
+            Node::Sqrt(base) => msqrt(*base).render_content(target),
+            Node::Root(base, index) => {
+                mroot(*base, *index).render_content(target)
+            }
+            Node::Sub(base, sub) => msub(*base, *sub).render_content(target),
+            Node::Sup(base, sup) => msup(*base, *sup).render_content(target),
+            Node::SubSup(base, sub, sup) => {
+                msubsup(*base, *sub, *sup).render_content(target)
+            }
+        }
+    }
+}
+
+
+//------------ TexParser -----------------------------------------------------
+
+struct TexParser<'a> {
+    src: &'a str,
+    pos: usize,
+
+    /// The number of atoms we are currently nested in.
+    depth: usize,
+}
+
+impl TexParser<'_> {
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.src[self.pos..];
+        let trimmed = rest.trim_start();
+        self.pos += rest.len() - trimmed.len();
+        trimmed.chars().next()
+    }
+
+    fn bump(&mut self, ch: char) {
+        self.pos += ch.len_utf8();
+    }
+
+    fn err<T>(&self, kind: TexErrorKind) -> Result<T, TexError> {
+        Err(TexError { offset: self.pos, kind })
+    }
+
+    /// Parses nodes until the closing character or the end.
+    fn row(&mut self, close: Option<char>) -> Result<Vec<Node>, TexError> {
+        let mut nodes = Vec::new();
+        loop {
+            let ch = match self.peek() {
+                Some(ch) => ch,
+                None if close.is_some() => {
+                    return self.err(TexErrorKind::UnexpectedEnd)
+                }
+                None => return Ok(nodes),
+            };
+            if Some(ch) == close {
+                self.bump(ch);
+                return Ok(nodes)
+            }
+            match ch {
+                '}' => return self.err(TexErrorKind::UnmatchedBrace),
+                '^' | '_' => {
+                    let base = match nodes.pop() {
+                        Some(base) => base,
+                        None => return self.err(TexErrorKind::MissingBase),
+                    };
+                    let offset = self.pos;
+                    self.bump(ch);
+                    let script = self.argument()?;
+                    match attach_script(base, ch == '^', script) {
+                        Some(node) => nodes.push(node),
+                        None => {
+                            return Err(TexError {
+                                offset, kind: TexErrorKind::DoubleScript
+                            })
+                        }
+                    }
+                }
+                _ => nodes.push(self.atom(false)?),
+            }
+        }
+    }
+
+    /// Parses the argument of a command or script.
+    fn argument(&mut self) -> Result<Node, TexError> {
+        match self.peek() {
+            Some(_) => self.atom(true),
+            None => self.err(TexErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Parses a group, a command, or a single token.
+    ///
+    /// If `single` is true, a number only consists of one digit as in
+    /// `x^23`.
+    ///
+    /// All nesting goes through here, so this is where the depth is
+    /// limited.
+    fn atom(&mut self, single: bool) -> Result<Node, TexError> {
+        if self.depth >= TEX_MAX_DEPTH {
+            return self.err(TexErrorKind::TooDeep)
+        }
+        self.depth += 1;
+        let res = self.nested_atom(single);
+        self.depth -= 1;
+        res
+    }
+
+    fn nested_atom(&mut self, single: bool) -> Result<Node, TexError> {
+        let rest = &self.src[self.pos..];
+        let ch = match rest.chars().next() {
+            Some(ch) => ch,
+            None => return self.err(TexErrorKind::UnexpectedEnd),
+        };
+        if ch == '{' {
+            self.bump(ch);
+            return Ok(Node::Row(self.row(Some('}'))?))
+        }
+        if ch == '}' {
+            return self.err(TexErrorKind::UnmatchedBrace)
+        }
+        if ch == '\\' {
+            return self.command()
+        }
+        if ch.is_ascii_digit() {
+            let len = if single {
+                1
+            }
+            else {
+                rest.find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+                    .unwrap_or(rest.len())
+            };
+            self.pos += len;
+            return Ok(Node::Number(rest[..len].into()))
+        }
+        self.bump(ch);
+        if ch.is_alphabetic() {
+            Ok(Node::Ident(ch.into()))
+        }
+        else {
+            Ok(Node::Op(ch.into()))
+        }
+    }
+
+    fn command(&mut self) -> Result<Node, TexError> {
+        let start = self.pos;
+        self.pos += 1;
+        let rest = &self.src[self.pos..];
+        let len = match rest.find(|ch: char| !ch.is_ascii_alphabetic()) {
+            Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+            Some(len) => len,
+            None => rest.len(),
+        };
+        if len == 0 {
+            return self.err(TexErrorKind::UnexpectedEnd)
+        }
+        let name = &rest[..len];
+        self.pos += len;
+        match name {
+            "frac" => {
+                let num = self.argument()?;
+                let den = self.argument()?;
+                return Ok(Node::Frac(Box::new(num), Box::new(den)))
+            }
+            "sqrt" => {
+                if self.peek() == Some('[') {
+                    self.bump('[');
+                    let index = Node::Row(self.row(Some(']'))?);
+                    let base = self.argument()?;
+                    return Ok(Node::Root(Box::new(base), Box::new(index)))
+                }
+                return Ok(Node::Sqrt(Box::new(self.argument()?)))
+            }
+            "text" => return self.text(),
+            "," => return Ok(Node::Space("0.1667em")),
+            ";" => return Ok(Node::Space("0.2778em")),
+            "quad" => return Ok(Node::Space("1em")),
+            _ => { }
+        }
+        if let Some(&(_, ch)) = GREEK.iter().find(|item| item.0 == name) {
+            return Ok(Node::Ident(ch.into()))
+        }
+        if let Some(&(_, op)) = SYMBOLS.iter().find(|item| item.0 == name) {
+            return Ok(Node::Op(op.into()))
+        }
+        if FUNCTIONS.contains(&name) {
+            return Ok(Node::Ident(name.into()))
+        }
+        Err(TexError {
+            offset: start,
+            kind: TexErrorKind::UnknownCommand(name.into()),
+        })
+    }
+
+    /// Parses the argument of `\text` which is taken literally.
+    fn text(&mut self) -> Result<Node, TexError> {
+        if self.peek() != Some('{') {
+            return self.err(TexErrorKind::UnexpectedEnd)
+        }
+        self.bump('{');
+        let rest = &self.src[self.pos..];
+        match rest.find('}') {
+            Some(end) => {
+                self.pos += end + 1;
+                Ok(Node::Text(rest[..end].into()))
+            }
+            None => {
+                self.pos = self.src.len();
+                self.err(TexErrorKind::UnexpectedEnd)
+            }
+        }
+    }
+}
+
+/// Attaches a sub- or superscript to a base.
+///
+/// Returns `None` if the base already has this kind of script.
+fn attach_script(base: Node, sup: bool, script: Node) -> Option<Node> {
+    let script = Box::new(script);
+    Some(match (base, sup) {
+        (Node::Sub(base, sub), true) => Node::SubSup(base, sub, script),
+        (Node::Sup(base, sup), false) => Node::SubSup(base, script, sup),
+        (Node::Sub(..) | Node::Sup(..) | Node::SubSup(..), _) => {
+            return None
+        }
+        (base, true) => Node::Sup(Box::new(base), script),
+        (base, false) => Node::Sub(Box::new(base), script),
+    })
+}
+
+const GREEK: &[(&str, char)] = &[
+    ("alpha", 'α'), ("beta", 'β'), ("gamma", 'γ'), ("delta", 'δ'),
+    ("epsilon", 'ε'), ("zeta", 'ζ'), ("eta", 'η'), ("theta", 'θ'),
+    ("iota", 'ι'), ("kappa", 'κ'), ("lambda", 'λ'), ("mu", 'μ'),
+    ("nu", 'ν'), ("xi", 'ξ'), ("pi", 'π'), ("rho", 'ρ'), ("sigma", 'σ'),
+    ("tau", 'τ'), ("upsilon", 'υ'), ("phi", 'φ'), ("chi", 'χ'),
+    ("psi", 'ψ'), ("omega", 'ω'),
+    ("Gamma", 'Γ'), ("Delta", 'Δ'), ("Theta", 'Θ'), ("Lambda", 'Λ'),
+    ("Xi", 'Ξ'), ("Pi", 'Π'), ("Sigma", 'Σ'), ("Upsilon", 'Υ'),
+    ("Phi", 'Φ'), ("Psi", 'Ψ'), ("Omega", 'Ω'),
+];
+
+const SYMBOLS: &[(&str, &str)] = &[
+    ("{", "{"), ("}", "}"), ("approx", "≈"), ("cdot", "⋅"),
+    ("cdots", "⋯"), ("div", "÷"), ("equiv", "≡"), ("forall", "∀"),
+    ("exists", "∃"), ("ge", "≥"), ("geq", "≥"), ("in", "∈"),
+    ("infty", "∞"), ("int", "∫"), ("ldots", "…"), ("le", "≤"),
+    ("leq", "≤"), ("mp", "∓"), ("nabla", "∇"), ("ne", "≠"), ("neq", "≠"),
+    ("notin", "∉"), ("partial", "∂"), ("pm", "±"), ("prod", "∏"),
+    ("rightarrow", "→"), ("leftarrow", "←"), ("Rightarrow", "⇒"),
+    ("subset", "⊂"), ("subseteq", "⊆"), ("sum", "∑"), ("times", "×"),
+    ("to", "→"),
+];
+
+const FUNCTIONS: &[&str] = &[
+    "arccos", "arcsin", "arctan", "cos", "cosh", "cot", "deg", "det",
+    "exp", "gcd", "lim", "ln", "log", "max", "min", "sin", "sinh", "tan",
+    "tanh",
+];
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(expr: &str) -> String {
+        let content = tex(expr).unwrap();
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    fn error(expr: &str) -> (usize, TexErrorKind) {
+        let err = tex(expr).unwrap_err();
+        (err.offset, err.kind)
+    }
+
+    #[test]
+    fn tex_tokens() {
+        assert_eq!(
+            render("x + 12.5 \\alpha \\sin \\le"),
+            "<mrow><mi>x</mi><mo>+</mo><mn>12.5</mn><mi>α</mi>\
+             <mi>sin</mi><mo>≤</mo></mrow>"
+        );
+    }
+
+    #[test]
+    fn tex_commands() {
+        assert_eq!(
+            render("\\frac{1}{x}"),
+            "<mrow><mfrac><mrow><mn>1</mn></mrow><mrow><mi>x</mi></mrow>\
+             </mfrac></mrow>"
+        );
+        assert_eq!(
+            render("\\sqrt[3]{x}\\sqrt 2"),
+            "<mrow><mroot><mrow><mi>x</mi></mrow><mrow><mn>3</mn></mrow>\
+             </mroot><msqrt><mn>2</mn></msqrt></mrow>"
+        );
+        assert_eq!(
+            render("\\text{a < b}\\,\\quad"),
+            "<mrow><mtext>a &lt; b</mtext><mspace width=\"0.1667em\"/>\
+             <mspace width=\"1em\"/></mrow>"
+        );
+    }
+
+    #[test]
+    fn tex_scripts() {
+        assert_eq!(
+            render("x^23"),
+            "<mrow><msup><mi>x</mi><mn>2</mn></msup><mn>3</mn></mrow>"
+        );
+        assert_eq!(
+            render("x_i^{n}"),
+            "<mrow><msubsup><mi>x</mi><mi>i</mi><mrow><mi>n</mi></mrow>\
+             </msubsup></mrow>"
+        );
+    }
+
+    #[test]
+    fn tex_errors() {
+        assert_eq!(error("{x"), (2, TexErrorKind::UnexpectedEnd));
+        assert_eq!(error("x}"), (1, TexErrorKind::UnmatchedBrace));
+        assert_eq!(error("^2"), (0, TexErrorKind::MissingBase));
+        assert_eq!(error("x^2^3"), (3, TexErrorKind::DoubleScript));
+        assert_eq!(
+            error("a \\foo"),
+            (2, TexErrorKind::UnknownCommand("foo".into()))
+        );
+        assert_eq!(error("\\frac{1}"), (8, TexErrorKind::UnexpectedEnd));
+        assert_eq!(error("\\text x"), (6, TexErrorKind::UnexpectedEnd));
+    }
+
+    #[test]
+    fn tex_depth() {
+        let ok = "{".repeat(TEX_MAX_DEPTH) + &"}".repeat(TEX_MAX_DEPTH);
+        assert!(tex(&ok).is_ok());
+        let deep = "{".repeat(TEX_MAX_DEPTH + 1)
+            + &"}".repeat(TEX_MAX_DEPTH + 1);
+        assert_eq!(
+            tex(&deep).unwrap_err(),
+            TexError { offset: TEX_MAX_DEPTH, kind: TexErrorKind::TooDeep }
+        );
+        let deep = "x^{".repeat(100_000);
+        assert_eq!(
+            tex(&deep).unwrap_err().kind, TexErrorKind::TooDeep
+        );
+        let deep = "\\frac".repeat(100_000);
+        assert_eq!(
+            tex(&deep).unwrap_err().kind, TexErrorKind::TooDeep
+        );
+    }
+}
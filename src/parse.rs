@@ -0,0 +1,363 @@
+//! A minimal tokenizer for rendered HTML.
+//!
+//! This is not a full HTML parser. It understands what this crate renders
+//! and is lenient enough to get through most other markup, too. It is used
+//! by the checkers that inspect rendered targets.
+
+use std::borrow::Cow;
+
+
+//------------ Token ---------------------------------------------------------
+
+/// A single token of an HTML document.
+#[derive(Clone, Debug)]
+pub enum Token<'a> {
+    /// A start tag.
+    StartTag {
+        name: &'a str,
+        attrs: Vec<Attribute<'a>>,
+        self_closing: bool,
+    },
+
+    /// An end tag.
+    EndTag {
+        name: &'a str,
+    },
+
+    /// Character data, still escaped.
+    Text(&'a str),
+
+    /// A comment, doctype, or other markup declaration.
+    Other,
+
+    /// Markup that could not be understood.
+    Malformed,
+}
+
+
+//------------ Attribute -----------------------------------------------------
+
+/// An attribute of a start tag.
+#[derive(Clone, Debug)]
+pub struct Attribute<'a> {
+    /// The attribute name.
+    pub name: &'a str,
+
+    /// The still escaped value of the attribute if there is one.
+    pub value: Option<&'a str>,
+
+    /// The byte offset of the attribute name.
+    pub offset: usize,
+}
+
+impl<'a> Attribute<'a> {
+    /// Returns the unescaped value of the attribute.
+    pub fn value(&self) -> Cow<'a, str> {
+        match self.value {
+            Some(value) => unescape(value),
+            None => Cow::Borrowed(""),
+        }
+    }
+}
+
+
+//------------ Tokenizer -----------------------------------------------------
+
+/// An iterator over the tokens and their byte offsets.
+pub struct Tokenizer<'a> {
+    data: &'a str,
+    pos: usize,
+
+    /// The name of the raw text element we are currently in.
+    raw_text: Option<&'a str>,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(data: &'a str) -> Self {
+        Tokenizer { data, pos: 0, raw_text: None }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.data[self.pos..]
+    }
+
+    fn raw_text(&mut self, name: &'a str) -> Token<'a> {
+        let rest = self.rest();
+        let end = find_ignore_case(rest, "</", name).unwrap_or(rest.len());
+        self.pos += end;
+        Token::Text(&rest[..end])
+    }
+
+    fn markup(&mut self) -> Token<'a> {
+        let rest = self.rest();
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            self.pos += match comment.find("-->") {
+                Some(end) => end + 7,
+                None => rest.len(),
+            };
+            return Token::Other
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            self.pos += rest.find('>').map_or(rest.len(), |end| end + 1);
+            return Token::Other
+        }
+        if let Some(tag) = rest.strip_prefix("</") {
+            let end = match tag.find('>') {
+                Some(end) => end,
+                None => {
+                    self.pos = self.data.len();
+                    return Token::Malformed
+                }
+            };
+            self.pos += end + 3;
+            let name = tag[..end].trim();
+            if name.is_empty() {
+                return Token::Malformed
+            }
+            return Token::EndTag { name }
+        }
+        self.start_tag()
+    }
+
+    fn start_tag(&mut self) -> Token<'a> {
+        let start = self.pos + 1;
+        let tag = &self.data[start..];
+        let name_len = tag.find(|ch: char| {
+            ch.is_ascii_whitespace() || ch == '/' || ch == '>'
+        }).unwrap_or(tag.len());
+        if name_len == 0 {
+            // A lone less-than sign is just text.
+            self.pos += 1;
+            return Token::Text(&self.data[start - 1..start])
+        }
+        let name = &tag[..name_len];
+        let mut pos = start + name_len;
+        let mut attrs = Vec::new();
+        loop {
+            let rest = &self.data[pos..];
+            let trimmed = rest.trim_start();
+            pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("/>") {
+                self.pos = pos + 2;
+                return Token::StartTag { name, attrs, self_closing: true }
+            }
+            if trimmed.starts_with('>') {
+                self.pos = pos + 1;
+                if is_raw_text(name) {
+                    self.raw_text = Some(name);
+                }
+                return Token::StartTag { name, attrs, self_closing: false }
+            }
+            if trimmed.is_empty() {
+                self.pos = self.data.len();
+                return Token::Malformed
+            }
+            if trimmed.starts_with('/') {
+                pos += 1;
+                continue
+            }
+            let attr_len = trimmed.find(|ch: char| {
+                ch.is_ascii_whitespace() || ch == '=' || ch == '>'
+                    || ch == '/'
+            }).unwrap_or(trimmed.len());
+            let attr_name = &trimmed[..attr_len];
+            let offset = pos;
+            pos += attr_len;
+            let value = match trimmed[attr_len..].strip_prefix('=') {
+                Some(value) => {
+                    pos += 1;
+                    match value.chars().next() {
+                        Some(quote @ ('"' | '\'')) => {
+                            let end = match value[1..].find(quote) {
+                                Some(end) => end,
+                                None => {
+                                    self.pos = self.data.len();
+                                    return Token::Malformed
+                                }
+                            };
+                            pos += end + 2;
+                            Some(&value[1..end + 1])
+                        }
+                        _ => {
+                            let end = value.find(|ch: char| {
+                                ch.is_ascii_whitespace() || ch == '>'
+                            }).unwrap_or(value.len());
+                            pos += end;
+                            Some(&value[..end])
+                        }
+                    }
+                }
+                None => None,
+            };
+            attrs.push(Attribute { name: attr_name, value, offset });
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = (usize, Token<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.pos;
+        let rest = self.rest();
+        if rest.is_empty() {
+            return None
+        }
+        if let Some(name) = self.raw_text.take() {
+            if find_ignore_case(rest, "</", name) != Some(0) {
+                return Some((offset, self.raw_text(name)))
+            }
+        }
+        if rest.starts_with('<') {
+            return Some((offset, self.markup()))
+        }
+        let end = rest.find('<').unwrap_or(rest.len());
+        self.pos += end;
+        Some((offset, Token::Text(&rest[..end])))
+    }
+}
+
+
+//------------ Helpers -------------------------------------------------------
+
+/// Returns whether the content of the element is raw text.
+fn is_raw_text(name: &str) -> bool {
+    name.eq_ignore_ascii_case("script") || name.eq_ignore_ascii_case("style")
+}
+
+/// Finds the first occurrence of `prefix` followed by `name`.
+///
+/// The name is compared ignoring ASCII case.
+fn find_ignore_case(data: &str, prefix: &str, name: &str) -> Option<usize> {
+    let mut start = 0;
+    while let Some(idx) = data[start..].find(prefix) {
+        let idx = start + idx;
+        let tail = &data.as_bytes()[idx + prefix.len()..];
+        if tail.len() >= name.len()
+            && tail[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+        {
+            return Some(idx)
+        }
+        start = idx + prefix.len();
+    }
+    None
+}
+
+/// Replaces the character references produced by escaping.
+pub fn unescape(s: &str) -> Cow<'_, str> {
+    if !s.contains('&') {
+        return Cow::Borrowed(s)
+    }
+    let mut res = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(idx) = rest.find('&') {
+        res.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let repl = [
+            ("&amp;", '&'), ("&lt;", '<'), ("&gt;", '>'),
+            ("&quot;", '"'), ("&apos;", '\''),
+        ].into_iter().find(|(entity, _)| rest.starts_with(entity));
+        match repl {
+            Some((entity, ch)) => {
+                res.push(ch);
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+    Cow::Owned(res)
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the tokens in a compact form for comparing.
+    fn tokens(data: &str) -> Vec<String> {
+        Tokenizer::new(data).map(|(offset, token)| {
+            let token = match token {
+                Token::StartTag { name, attrs, self_closing } => {
+                    let attrs: Vec<_> = attrs.iter().map(|attr| {
+                        format!(
+                            "{}@{}={:?}", attr.name, attr.offset, attr.value
+                        )
+                    }).collect();
+                    format!(
+                        "<{} {}{}>", name, attrs.join(" "),
+                        if self_closing { "/" } else { "" }
+                    )
+                }
+                Token::EndTag { name } => format!("</{}>", name),
+                Token::Text(text) => format!("{:?}", text),
+                Token::Other => "other".into(),
+                Token::Malformed => "malformed".into(),
+            };
+            format!("{}:{}", offset, token)
+        }).collect()
+    }
+
+    #[test]
+    fn tags_and_text() {
+        assert_eq!(
+            tokens("<p class=\"a b\" hidden id='x' n=1>a &amp; b</p><br/>"),
+            [
+                "0:<p class@3=Some(\"a b\") hidden@15=None id@22=Some(\"x\") \
+                 n@29=Some(\"1\")>",
+                "33:\"a &amp; b\"",
+                "42:</p>",
+                "46:<br />",
+            ]
+        );
+    }
+
+    #[test]
+    fn other_markup() {
+        assert_eq!(
+            tokens("<!DOCTYPE html><!-- a <p> --><?x?>a < b"),
+            ["0:other", "15:other", "29:other", "34:\"a \"", "36:\"<\"",
+             "37:\" b\""]
+        );
+    }
+
+    #[test]
+    fn raw_text() {
+        assert_eq!(
+            tokens("<script>if (a</b) {}</SCRIPT><style>p>a{}</style>"),
+            [
+                "0:<script >", "8:\"if (a</b) {}\"", "20:</SCRIPT>",
+                "29:<style >", "36:\"p>a{}\"", "41:</style>",
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(tokens("<p class=\"a>"), ["0:malformed"]);
+        assert_eq!(tokens("<p class"), ["0:malformed"]);
+        assert_eq!(tokens("a</p"), ["0:\"a\"", "1:malformed"]);
+        assert_eq!(tokens("</ >"), ["0:malformed"]);
+    }
+
+    #[test]
+    fn attribute_value() {
+        let attr = Attribute {
+            name: "title", value: Some("&lt;a&gt; &amp;amp; &x;"), offset: 0
+        };
+        assert_eq!(attr.value(), "<a> &amp; &x;");
+        let attr = Attribute { name: "hidden", value: None, offset: 0 };
+        assert_eq!(attr.value(), "");
+    }
+
+    #[test]
+    fn unescape_borrows() {
+        assert!(matches!(unescape("abc"), Cow::Borrowed("abc")));
+        assert_eq!(unescape("&quot;&apos;&"), "\"'&");
+    }
+}
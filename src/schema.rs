@@ -0,0 +1,431 @@
+//! Schema.org items marked up with microdata.
+//!
+//! The types in this module render common schema.org types using the
+//! elements of the [`html`][crate::html] module with the microdata
+//! attributes search engines look for. The items are flow content.
+
+use crate::core::{Attributes, Content, Flow, Target};
+use crate::html::attr::{self, aria, Datetime};
+use crate::html::{a, div, img, li, link, meta, nav, ol, p, span, time};
+use crate::utils::{display, iter, Either};
+
+
+//------------ Product -------------------------------------------------------
+
+/// A product with an optional offer.
+#[derive(Clone, Debug)]
+pub struct Product<'a> {
+    name: &'a str,
+    description: Option<&'a str>,
+    image: Option<&'a str>,
+    brand: Option<&'a str>,
+    sku: Option<&'a str>,
+    offer: Option<Offer<'a>>,
+}
+
+impl<'a> Product<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Product {
+            name,
+            description: None,
+            image: None,
+            brand: None,
+            sku: None,
+            offer: None,
+        }
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the URL of an image of the product.
+    ///
+    /// The name of the product is used as the image’s alternative text.
+    pub fn image(mut self, src: &'a str) -> Self {
+        self.image = Some(src);
+        self
+    }
+
+    pub fn brand(mut self, brand: &'a str) -> Self {
+        self.brand = Some(brand);
+        self
+    }
+
+    pub fn sku(mut self, sku: &'a str) -> Self {
+        self.sku = Some(sku);
+        self
+    }
+
+    pub fn offer(mut self, offer: Offer<'a>) -> Self {
+        self.offer = Some(offer);
+        self
+    }
+}
+
+impl Content for Product<'_> {
+    fn render_content(self, target: &mut Target) {
+        div::attrs(item("https://schema.org/Product"), (
+            self.image.map(|src| {
+                img::attrs((
+                    attr::itemprop("image"),
+                    attr::src(src),
+                    attr::alt(self.name),
+                ))
+            }),
+            p::attrs(attr::itemprop("name"), self.name),
+            self.description.map(|desc| {
+                p::attrs(attr::itemprop("description"), desc)
+            }),
+            self.brand.map(|brand| {
+                p::attrs(
+                    (
+                        attr::itemprop("brand"),
+                        item("https://schema.org/Brand"),
+                    ),
+                    span::attrs(attr::itemprop("name"), brand)
+                )
+            }),
+            self.sku.map(|sku| meta::itemprop("sku", sku)),
+            self.offer,
+        )).render_content(target)
+    }
+}
+
+impl Flow for Product<'_> { }
+
+
+//------------ Offer ---------------------------------------------------------
+
+/// An offer to sell a product.
+#[derive(Clone, Debug)]
+pub struct Offer<'a> {
+    price: &'a str,
+    currency: &'a str,
+    availability: Option<Availability>,
+}
+
+impl<'a> Offer<'a> {
+    /// Creates an offer.
+    ///
+    /// The price should use a dot as the decimal separator, e.g., `12.50`.
+    /// The currency is an ISO 4217 code such as `EUR`.
+    pub fn new(price: &'a str, currency: &'a str) -> Self {
+        Offer { price, currency, availability: None }
+    }
+
+    pub fn availability(mut self, availability: Availability) -> Self {
+        self.availability = Some(availability);
+        self
+    }
+}
+
+impl Content for Offer<'_> {
+    fn render_content(self, target: &mut Target) {
+        let attrs = (
+            attr::itemprop("offers"), item("https://schema.org/Offer")
+        );
+        p::attrs(attrs, (
+            span::attrs(attr::itemprop("price"), self.price),
+            " ",
+            span::attrs(attr::itemprop("priceCurrency"), self.currency),
+            self.availability.map(|availability| {
+                link::itemprop("availability", availability.url())
+            }),
+        )).render_content(target)
+    }
+}
+
+impl Flow for Offer<'_> { }
+
+
+//------------ Availability --------------------------------------------------
+
+/// The availability of an offered product.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Availability {
+    BackOrder,
+    Discontinued,
+    InStock,
+    InStoreOnly,
+    LimitedAvailability,
+    OnlineOnly,
+    OutOfStock,
+    PreOrder,
+    SoldOut,
+}
+
+impl Availability {
+    /// Returns the schema.org URL of the availability.
+    pub fn url(self) -> &'static str {
+        match self {
+            Availability::BackOrder => "https://schema.org/BackOrder",
+            Availability::Discontinued => "https://schema.org/Discontinued",
+            Availability::InStock => "https://schema.org/InStock",
+            Availability::InStoreOnly => "https://schema.org/InStoreOnly",
+            Availability::LimitedAvailability => {
+                "https://schema.org/LimitedAvailability"
+            }
+            Availability::OnlineOnly => "https://schema.org/OnlineOnly",
+            Availability::OutOfStock => "https://schema.org/OutOfStock",
+            Availability::PreOrder => "https://schema.org/PreOrder",
+            Availability::SoldOut => "https://schema.org/SoldOut",
+        }
+    }
+}
+
+
+//------------ Event ---------------------------------------------------------
+
+/// An event happening at a certain time and place.
+#[derive(Clone, Debug)]
+pub struct Event<'a> {
+    name: &'a str,
+    start: (Datetime, &'a str),
+    end: Option<(Datetime, &'a str)>,
+    location: Option<(&'a str, &'a str)>,
+    description: Option<&'a str>,
+    url: Option<&'a str>,
+}
+
+impl<'a> Event<'a> {
+    /// Creates an event.
+    ///
+    /// The start is given as a date or date and time together with the
+    /// text to show for it.
+    pub fn new(name: &'a str, start: Datetime, start_text: &'a str) -> Self {
+        Event {
+            name,
+            start: (start, start_text),
+            end: None,
+            location: None,
+            description: None,
+            url: None,
+        }
+    }
+
+    /// Sets the end of the event and the text to show for it.
+    pub fn end(mut self, end: Datetime, text: &'a str) -> Self {
+        self.end = Some((end, text));
+        self
+    }
+
+    /// Sets the name and address of the place the event happens at.
+    pub fn location(mut self, name: &'a str, address: &'a str) -> Self {
+        self.location = Some((name, address));
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the URL of the event’s page and turns the name into a link.
+    pub fn url(mut self, url: &'a str) -> Self {
+        self.url = Some(url);
+        self
+    }
+}
+
+impl Content for Event<'_> {
+    fn render_content(self, target: &mut Target) {
+        let name = span::attrs(attr::itemprop("name"), self.name);
+        div::attrs(item("https://schema.org/Event"), (
+            p(match self.url {
+                Some(url) => Either::Left(
+                    a::attrs((attr::itemprop("url"), attr::href(url)), name)
+                ),
+                None => Either::Right(name),
+            }),
+            p((
+                time::attrs(
+                    (
+                        attr::itemprop("startDate"),
+                        attr::datetime(self.start.0),
+                    ),
+                    self.start.1
+                ),
+                self.end.map(|(end, text)| {
+                    (
+                        " – ",
+                        time::attrs(
+                            (attr::itemprop("endDate"), attr::datetime(end)),
+                            text
+                        )
+                    )
+                }),
+            )),
+            self.location.map(|(name, address)| {
+                let attrs = (
+                    attr::itemprop("location"),
+                    item("https://schema.org/Place"),
+                );
+                p::attrs(attrs, (
+                    span::attrs(attr::itemprop("name"), name),
+                    ", ",
+                    span::attrs(attr::itemprop("address"), address),
+                ))
+            }),
+            self.description.map(|desc| {
+                p::attrs(attr::itemprop("description"), desc)
+            }),
+        )).render_content(target)
+    }
+}
+
+impl Flow for Event<'_> { }
+
+
+//------------ BreadcrumbList ------------------------------------------------
+
+/// The trail of pages leading to the current page.
+///
+/// The list is rendered as an ordered list inside a `nav` element. The
+/// last item is marked as the current page.
+#[derive(Clone, Debug, Default)]
+pub struct BreadcrumbList<'a> {
+    items: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> BreadcrumbList<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a page with its name and URL.
+    pub fn item(mut self, name: &'a str, url: &'a str) -> Self {
+        self.items.push((name, url));
+        self
+    }
+}
+
+impl Content for BreadcrumbList<'_> {
+    fn render_content(self, target: &mut Target) {
+        let last = self.items.len();
+        nav::attrs(aria::label("Breadcrumb"),
+            ol::attrs(item("https://schema.org/BreadcrumbList"), iter(
+                self.items.into_iter().enumerate().map(|(idx, (name, url))| {
+                    li::attrs(
+                        (
+                            attr::itemprop("itemListElement"),
+                            item("https://schema.org/ListItem"),
+                        ),
+                        (
+                            a::attrs(
+                                (
+                                    attr::itemprop("item"),
+                                    attr::href(url),
+                                    (idx + 1 == last).then(|| {
+                                        aria::current(aria::Current::Page)
+                                    }),
+                                ),
+                                span::attrs(attr::itemprop("name"), name)
+                            ),
+                            meta::itemprop("position", display(idx + 1)),
+                        )
+                    )
+                })
+            ))
+        ).render_content(target)
+    }
+}
+
+impl Flow for BreadcrumbList<'_> { }
+
+
+//------------ Helpers -------------------------------------------------------
+
+/// Returns the attributes starting a new item of the given type URL.
+fn item(schema_type: &'static str) -> impl Attributes {
+    (
+        attr::itemscope(true),
+        attr::itemtype(schema_type),
+    )
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::attr::Date;
+    use crate::html::strict;
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn product() {
+        assert_eq!(
+            render(strict::div(
+                Product::new("Mug")
+                    .brand("Acme")
+                    .sku("m-1")
+                    .offer(
+                        Offer::new("12.50", "EUR")
+                            .availability(Availability::InStock)
+                    )
+            )),
+            "<div><div itemscope itemtype=\"https://schema.org/Product\">\
+             <p itemprop=\"name\">Mug</p>\
+             <p itemprop=\"brand\" itemscope \
+             itemtype=\"https://schema.org/Brand\">\
+             <span itemprop=\"name\">Acme</span></p>\
+             <meta itemprop=\"sku\" content=\"m-1\"/>\
+             <p itemprop=\"offers\" itemscope \
+             itemtype=\"https://schema.org/Offer\">\
+             <span itemprop=\"price\">12.50</span> \
+             <span itemprop=\"priceCurrency\">EUR</span>\
+             <link itemprop=\"availability\" \
+             href=\"https://schema.org/InStock\"/></p></div></div>"
+        );
+    }
+
+    #[test]
+    fn event() {
+        let date = Date::new(2024, 5, 1);
+        assert_eq!(
+            render(
+                Event::new("Meetup", Datetime::Date(date), "1 May")
+                    .url("/meetup")
+                    .location("Hall", "Main St")
+            ),
+            "<div itemscope itemtype=\"https://schema.org/Event\">\
+             <p><a itemprop=\"url\" href=\"/meetup\">\
+             <span itemprop=\"name\">Meetup</span></a></p>\
+             <p><time itemprop=\"startDate\" datetime=\"2024-05-01\">\
+             1 May</time></p>\
+             <p itemprop=\"location\" itemscope \
+             itemtype=\"https://schema.org/Place\">\
+             <span itemprop=\"name\">Hall</span>, \
+             <span itemprop=\"address\">Main St</span></p></div>"
+        );
+    }
+
+    #[test]
+    fn breadcrumb_list() {
+        assert_eq!(
+            render(
+                BreadcrumbList::new()
+                    .item("Home", "/")
+                    .item("Blog", "/blog/")
+            ),
+            "<nav aria-label=\"Breadcrumb\">\
+             <ol itemscope itemtype=\"https://schema.org/BreadcrumbList\">\
+             <li itemprop=\"itemListElement\" itemscope \
+             itemtype=\"https://schema.org/ListItem\">\
+             <a itemprop=\"item\" href=\"/\">\
+             <span itemprop=\"name\">Home</span></a>\
+             <meta itemprop=\"position\" content=\"1\"/></li>\
+             <li itemprop=\"itemListElement\" itemscope \
+             itemtype=\"https://schema.org/ListItem\">\
+             <a itemprop=\"item\" href=\"/blog/\" aria-current=\"page\">\
+             <span itemprop=\"name\">Blog</span></a>\
+             <meta itemprop=\"position\" content=\"2\"/></li></ol></nav>"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Metadata for search engines and social media.
+//!
+//! The [`PageMeta`] type collects the metadata of a page and renders the
+//! corresponding elements for the document’s `head`: the title, the
+//! description, a canonical link, OpenGraph properties, Twitter card tags,
+//! and JSON-LD structured data.
+
+use crate::core::{Attr, Content, Element, Target};
+use crate::escape;
+use crate::html::{link, meta, title};
+use crate::utils::iter;
+
+
+//------------ PageMeta ------------------------------------------------------
+
+/// The metadata of a page.
+///
+/// OpenGraph properties are derived from the title, description, and
+/// canonical URL. Twitter cards fall back to the OpenGraph properties, so
+/// only the tags specific to Twitter are rendered for them.
+#[derive(Clone, Debug)]
+pub struct PageMeta<'a> {
+    title: &'a str,
+    description: Option<&'a str>,
+    canonical: Option<&'a str>,
+    og_type: &'a str,
+    site_name: Option<&'a str>,
+    locale: Option<&'a str>,
+    image: Option<&'a str>,
+    image_alt: Option<&'a str>,
+    twitter_card: Option<TwitterCard>,
+    twitter_site: Option<&'a str>,
+    twitter_creator: Option<&'a str>,
+    robots: Option<&'a str>,
+    json_ld: Vec<&'a str>,
+}
+
+impl<'a> PageMeta<'a> {
+    pub fn new(title: &'a str) -> Self {
+        PageMeta {
+            title,
+            description: None,
+            canonical: None,
+            og_type: "website",
+            site_name: None,
+            locale: None,
+            image: None,
+            image_alt: None,
+            twitter_card: None,
+            twitter_site: None,
+            twitter_creator: None,
+            robots: None,
+            json_ld: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the preferred URL of the page.
+    ///
+    /// The URL should be absolute as it is also used as `og:url`.
+    pub fn canonical(mut self, url: &'a str) -> Self {
+        self.canonical = Some(url);
+        self
+    }
+
+    /// Sets the OpenGraph type of the page, `website` by default.
+    pub fn og_type(mut self, og_type: &'a str) -> Self {
+        self.og_type = og_type;
+        self
+    }
+
+    pub fn site_name(mut self, site_name: &'a str) -> Self {
+        self.site_name = Some(site_name);
+        self
+    }
+
+    /// Sets the locale of the page, e.g., `en_US`.
+    pub fn locale(mut self, locale: &'a str) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Sets the URL and alternative text of the image shown in previews.
+    pub fn image(mut self, url: &'a str, alt: &'a str) -> Self {
+        self.image = Some(url);
+        self.image_alt = Some(alt);
+        self
+    }
+
+    /// Sets the kind of Twitter card.
+    ///
+    /// If no card is set, a large image card is used for pages with an
+    /// image and a summary card otherwise.
+    pub fn twitter_card(mut self, card: TwitterCard) -> Self {
+        self.twitter_card = Some(card);
+        self
+    }
+
+    /// Sets the Twitter handle of the site, e.g., `@example`.
+    pub fn twitter_site(mut self, handle: &'a str) -> Self {
+        self.twitter_site = Some(handle);
+        self
+    }
+
+    /// Sets the Twitter handle of the author.
+    pub fn twitter_creator(mut self, handle: &'a str) -> Self {
+        self.twitter_creator = Some(handle);
+        self
+    }
+
+    /// Sets the directives for crawlers, e.g., `noindex, nofollow`.
+    pub fn robots(mut self, robots: &'a str) -> Self {
+        self.robots = Some(robots);
+        self
+    }
+
+    /// Adds a block of JSON-LD structured data.
+    ///
+    /// The data must already be serialized JSON. It is escaped for use
+    /// inside a `script` element when rendered.
+    pub fn json_ld(mut self, json: &'a str) -> Self {
+        self.json_ld.push(json);
+        self
+    }
+}
+
+impl Content for PageMeta<'_> {
+    fn render_content(self, target: &mut Target) {
+        let card = self.twitter_card.unwrap_or(
+            if self.image.is_some() { TwitterCard::SummaryLargeImage }
+            else { TwitterCard::Summary }
+        );
+        (
+            (
+                title(self.title),
+                self.description.map(|desc| meta::name("description", desc)),
+                self.robots.map(|robots| meta::name("robots", robots)),
+                self.canonical.map(link::canonical),
+            ),
+            (
+                meta::property("og:type", self.og_type),
+                meta::property("og:title", self.title),
+                self.description.map(|desc| {
+                    meta::property("og:description", desc)
+                }),
+                self.canonical.map(|url| meta::property("og:url", url)),
+                self.site_name.map(|name| {
+                    meta::property("og:site_name", name)
+                }),
+                self.locale.map(|locale| {
+                    meta::property("og:locale", locale)
+                }),
+                self.image.map(|url| meta::property("og:image", url)),
+                self.image_alt.map(|alt| {
+                    meta::property("og:image:alt", alt)
+                }),
+            ),
+            (
+                meta::name("twitter:card", card.as_str()),
+                self.twitter_site.map(|site| {
+                    meta::name("twitter:site", site)
+                }),
+                self.twitter_creator.map(|creator| {
+                    meta::name("twitter:creator", creator)
+                }),
+            ),
+            iter(self.json_ld.into_iter().map(json_ld)),
+        ).render_content(target)
+    }
+}
+
+
+//------------ TwitterCard ---------------------------------------------------
+
+/// The kind of card shown when a page is shared on Twitter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TwitterCard {
+    Summary,
+    SummaryLargeImage,
+}
+
+impl TwitterCard {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TwitterCard::Summary => "summary",
+            TwitterCard::SummaryLargeImage => "summary_large_image",
+        }
+    }
+}
+
+
+//------------ json_ld -------------------------------------------------------
+
+/// Creates a script element with JSON-LD structured data.
+///
+/// The data must already be serialized JSON. Characters that could end
+/// the script element are replaced by Unicode escapes.
+pub fn json_ld(json: &str) -> impl Content + '_ {
+    Element::new(
+        "script", Attr::new("type", "application/ld+json"), Json(json)
+    )
+}
+
+struct Json<'a>(&'a str);
+
+impl Content for Json<'_> {
+    fn render_content(self, target: &mut Target) {
+        escape::render_json(self.0, target)
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn page_meta() {
+        assert_eq!(
+            render(
+                PageMeta::new("Home")
+                    .description("A page")
+                    .canonical("https://example.com/")
+                    .image("https://example.com/a.png", "A")
+                    .twitter_site("@example")
+                    .json_ld(r#"{"name":"</script>"}"#)
+            ),
+            "<title>Home</title>\
+             <meta name=\"description\" content=\"A page\"/>\
+             <link rel=\"canonical\" href=\"https://example.com/\"/>\
+             <meta property=\"og:type\" content=\"website\"/>\
+             <meta property=\"og:title\" content=\"Home\"/>\
+             <meta property=\"og:description\" content=\"A page\"/>\
+             <meta property=\"og:url\" content=\"https://example.com/\"/>\
+             <meta property=\"og:image\" \
+                content=\"https://example.com/a.png\"/>\
+             <meta property=\"og:image:alt\" content=\"A\"/>\
+             <meta name=\"twitter:card\" content=\"summary_large_image\"/>\
+             <meta name=\"twitter:site\" content=\"@example\"/>\
+             <script type=\"application/ld+json\">\
+             {\"name\":\"\\u003c/script\\u003e\"}</script>"
+        );
+    }
+
+    #[test]
+    fn twitter_card_fallback() {
+        assert!(
+            render(PageMeta::new("Home")).contains(
+                "<meta name=\"twitter:card\" content=\"summary\"/>"
+            )
+        );
+        assert!(
+            render(
+                PageMeta::new("Home")
+                    .image("a.png", "A")
+                    .twitter_card(TwitterCard::Summary)
+            ).contains(
+                "<meta name=\"twitter:card\" content=\"summary\"/>"
+            )
+        );
+    }
+}
@@ -0,0 +1,504 @@
+//! Inline SVG.
+//!
+//! The elements in this module are meant to be embedded in HTML. Since SVG
+//! is foreign content, elements without content are rendered using
+//! self-closing syntax.
+
+use std::fmt::Write;
+use crate::core::{
+    Attributes, AttributeValue, Content, Element, EmptyElement,
+    PhrasingContent, Target,
+};
+
+//------------ Elements ------------------------------------------------------
+
+macro_rules! container {
+    ( $name:ident, $tag:expr ) => {
+        pub fn $name(
+            attrs: impl Attributes, content: impl Content
+        ) -> impl Content {
+            Element::new($tag, attrs, content)
+        }
+    }
+}
+
+macro_rules! shape {
+    ( $name:ident, $tag:expr ) => {
+        pub fn $name(attrs: impl Attributes) -> impl Content {
+            EmptyElement::new($tag, attrs)
+        }
+
+        pub mod $name {
+            use super::*;
+
+            /// Creates the element with content such as a `title`.
+            pub fn content(
+                attrs: impl Attributes, content: impl Content
+            ) -> impl Content {
+                Element::new($tag, attrs, content)
+            }
+        }
+    }
+}
+
+//--- svg
+
+/// Creates the root element of an inline SVG image.
+///
+/// The element is phrasing content and can thus be used with the
+/// [strict][crate::html::strict] elements. Its content is SVG and not
+/// subject to the HTML categories.
+pub fn svg(
+    attrs: impl Attributes, content: impl Content
+) -> PhrasingContent<impl Content> {
+    PhrasingContent::new(Element::new("svg", attrs, content))
+}
+
+//--- circle
+
+shape!(circle, "circle");
+
+//--- clip_path
+
+container!(clip_path, "clipPath");
+
+//--- defs
+
+container!(defs, "defs");
+
+//--- desc
+
+pub fn desc(content: impl Content) -> impl Content {
+    Element::new("desc", (), content)
+}
+
+//--- g
+
+container!(g, "g");
+
+//--- line
+
+shape!(line, "line");
+
+//--- linear_gradient
+
+container!(linear_gradient, "linearGradient");
+
+//--- path
+
+pub fn path(d: impl AttributeValue, attrs: impl Attributes) -> impl Content {
+    EmptyElement::new("path", (attr::d(d), attrs))
+}
+
+pub mod path {
+    use super::*;
+
+    /// Creates the element with content such as a `title`.
+    pub fn content(
+        d: impl AttributeValue, attrs: impl Attributes, content: impl Content
+    ) -> impl Content {
+        Element::new("path", (attr::d(d), attrs), content)
+    }
+}
+
+//--- polygon, polyline
+
+shape!(polygon, "polygon");
+shape!(polyline, "polyline");
+
+//--- rect
+
+shape!(rect, "rect");
+
+//--- stop
+
+pub fn stop(offset: f64, color: impl AttributeValue) -> impl Content {
+    EmptyElement::new("stop", (attr::offset(offset), attr::stop_color(color)))
+}
+
+//--- symbol
+
+container!(symbol, "symbol");
+
+//--- text, tspan
+
+container!(text, "text");
+container!(tspan, "tspan");
+
+//--- title
+
+pub fn title(content: impl Content) -> impl Content {
+    Element::new("title", (), content)
+}
+
+//--- use_
+
+pub fn use_(
+    href: impl AttributeValue, attrs: impl Attributes
+) -> impl Content {
+    EmptyElement::new("use", (attr::href(href), attrs))
+}
+
+
+//------------ Attributes ----------------------------------------------------
+
+pub mod attr {
+    use crate::core::{Attr, Attributes, AttributeValue, Target};
+    use crate::utils::display;
+
+    pub use crate::html::attr::{class, data, id, lang, style};
+
+    pub fn clip_path(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("clip-path", value)
+    }
+
+    pub fn cx(value: f64) -> impl Attributes {
+        Attr::new("cx", display(value))
+    }
+
+    pub fn cy(value: f64) -> impl Attributes {
+        Attr::new("cy", display(value))
+    }
+
+    pub fn d(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("d", value)
+    }
+
+    pub fn dominant_baseline(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("dominant-baseline", value)
+    }
+
+    pub fn dx(value: f64) -> impl Attributes {
+        Attr::new("dx", display(value))
+    }
+
+    pub fn dy(value: f64) -> impl Attributes {
+        Attr::new("dy", display(value))
+    }
+
+    pub fn fill(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("fill", value)
+    }
+
+    pub fn fill_opacity(value: f64) -> impl Attributes {
+        Attr::new("fill-opacity", display(value))
+    }
+
+    pub fn focusable(value: bool) -> impl Attributes {
+        Attr::new("focusable", if value { "true" } else { "false" })
+    }
+
+    pub fn font_family(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("font-family", value)
+    }
+
+    pub fn font_size(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("font-size", value)
+    }
+
+    pub fn gradient_units(value: Units) -> impl Attributes {
+        Attr::new("gradientUnits", value)
+    }
+
+    pub fn height(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("height", value)
+    }
+
+    pub fn href(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("href", value)
+    }
+
+    pub fn offset(value: f64) -> impl Attributes {
+        Attr::new("offset", display(value))
+    }
+
+    pub fn opacity(value: f64) -> impl Attributes {
+        Attr::new("opacity", display(value))
+    }
+
+    /// Creates a `points` attribute for `polygon` and `polyline`.
+    pub fn points(
+        value: impl IntoIterator<Item = (f64, f64)>
+    ) -> impl Attributes {
+        Attr::new("points", Points(value.into_iter()))
+    }
+
+    pub fn preserve_aspect_ratio(
+        value: impl AttributeValue
+    ) -> impl Attributes {
+        Attr::new("preserveAspectRatio", value)
+    }
+
+    pub fn r(value: f64) -> impl Attributes {
+        Attr::new("r", display(value))
+    }
+
+    pub fn rx(value: f64) -> impl Attributes {
+        Attr::new("rx", display(value))
+    }
+
+    pub fn ry(value: f64) -> impl Attributes {
+        Attr::new("ry", display(value))
+    }
+
+    pub fn stop_color(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("stop-color", value)
+    }
+
+    pub fn stroke(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("stroke", value)
+    }
+
+    pub fn stroke_dasharray(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("stroke-dasharray", value)
+    }
+
+    pub fn stroke_linecap(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("stroke-linecap", value)
+    }
+
+    pub fn stroke_linejoin(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("stroke-linejoin", value)
+    }
+
+    pub fn stroke_width(value: f64) -> impl Attributes {
+        Attr::new("stroke-width", display(value))
+    }
+
+    pub fn text_anchor(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("text-anchor", value)
+    }
+
+    pub fn transform(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("transform", value)
+    }
+
+    pub fn view_box(
+        min_x: f64, min_y: f64, width: f64, height: f64
+    ) -> impl Attributes {
+        Attr::new("viewBox", ViewBox([min_x, min_y, width, height]))
+    }
+
+    pub fn width(value: impl AttributeValue) -> impl Attributes {
+        Attr::new("width", value)
+    }
+
+    pub fn x(value: f64) -> impl Attributes {
+        Attr::new("x", display(value))
+    }
+
+    pub fn x1(value: f64) -> impl Attributes {
+        Attr::new("x1", display(value))
+    }
+
+    pub fn x2(value: f64) -> impl Attributes {
+        Attr::new("x2", display(value))
+    }
+
+    pub fn xmlns() -> impl Attributes {
+        Attr::new("xmlns", "http://www.w3.org/2000/svg")
+    }
+
+    pub fn y(value: f64) -> impl Attributes {
+        Attr::new("y", display(value))
+    }
+
+    pub fn y1(value: f64) -> impl Attributes {
+        Attr::new("y1", display(value))
+    }
+
+    pub fn y2(value: f64) -> impl Attributes {
+        Attr::new("y2", display(value))
+    }
+
+
+    //--- Points
+
+    struct Points<I>(I);
+
+    impl<I: Iterator<Item = (f64, f64)>> AttributeValue for Points<I> {
+        fn render_attr_value(self, target: &mut Target) {
+            for (idx, (x, y)) in self.0.enumerate() {
+                if idx > 0 {
+                    target.append_slice(b" ");
+                }
+                format_args!("{},{}", x, y).render_attr_value(target);
+            }
+        }
+    }
+
+
+    //--- Units
+
+    /// The coordinate system of gradients and clip paths.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Units {
+        UserSpaceOnUse,
+        ObjectBoundingBox,
+    }
+
+    impl AttributeValue for Units {
+        fn render_attr_value(self, target: &mut Target) {
+            target.append_slice(match self {
+                Units::UserSpaceOnUse => b"userSpaceOnUse",
+                Units::ObjectBoundingBox => b"objectBoundingBox",
+            })
+        }
+    }
+
+
+    //--- ViewBox
+
+    struct ViewBox([f64; 4]);
+
+    impl AttributeValue for ViewBox {
+        fn render_attr_value(self, target: &mut Target) {
+            let [min_x, min_y, width, height] = self.0;
+            format_args!(
+                "{} {} {} {}", min_x, min_y, width, height
+            ).render_attr_value(target)
+        }
+    }
+}
+
+
+//------------ PathData ------------------------------------------------------
+
+/// A builder for the `d` attribute of a `path` element.
+///
+/// Each method appends one command to the path using absolute
+/// coordinates.
+#[derive(Clone, Debug, Default)]
+pub struct PathData {
+    buf: String,
+}
+
+impl PathData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Starts a new sub-path at the given point (`M`).
+    pub fn move_to(self, x: f64, y: f64) -> Self {
+        self.command(format_args!("M{} {}", x, y))
+    }
+
+    /// Draws a straight line to the given point (`L`).
+    pub fn line_to(self, x: f64, y: f64) -> Self {
+        self.command(format_args!("L{} {}", x, y))
+    }
+
+    /// Draws a cubic Bézier curve to `(x, y)` (`C`).
+    ///
+    /// The curve uses `(x1, y1)` and `(x2, y2)` as its control points.
+    pub fn curve_to(
+        self, x1: f64, y1: f64, x2: f64, y2: f64, x: f64, y: f64
+    ) -> Self {
+        self.command(
+            format_args!("C{} {} {} {} {} {}", x1, y1, x2, y2, x, y)
+        )
+    }
+
+    /// Closes the current sub-path (`Z`).
+    pub fn close(self) -> Self {
+        self.command(format_args!("Z"))
+    }
+
+    fn command(mut self, args: std::fmt::Arguments) -> Self {
+        if !self.buf.is_empty() {
+            self.buf.push(' ');
+        }
+        self.buf.write_fmt(args).unwrap();
+        self
+    }
+}
+
+impl AttributeValue for PathData {
+    fn render_attr_value(self, target: &mut Target) {
+        target.append_slice(self.buf.as_bytes())
+    }
+}
+
+impl AttributeValue for &PathData {
+    fn render_attr_value(self, target: &mut Target) {
+        target.append_slice(self.buf.as_bytes())
+    }
+}
+
+
+//============ Tests =========================================================
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::strict;
+
+    fn render(content: impl Content) -> String {
+        String::from_utf8(content.render().as_ref().into()).unwrap()
+    }
+
+    #[test]
+    fn path_data() {
+        assert_eq!(
+            render(path(
+                PathData::new()
+                    .move_to(0., 1.5)
+                    .line_to(10., -2.)
+                    .curve_to(1., 2., 3., 4., 5., 6.)
+                    .close(),
+                attr::fill("none")
+            )),
+            "<path d=\"M0 1.5 L10 -2 C1 2 3 4 5 6 Z\" fill=\"none\"/>"
+        );
+        assert!(PathData::new().is_empty());
+    }
+
+    #[test]
+    fn camel_case_names() {
+        assert_eq!(
+            render(svg(
+                (attr::view_box(0., 0., 10., 5.5), attr::xmlns()),
+                defs((), (
+                    clip_path(attr::id("c"), rect(attr::width("10"))),
+                    linear_gradient(
+                        attr::gradient_units(attr::Units::UserSpaceOnUse),
+                        stop(0., "red")
+                    ),
+                ))
+            )),
+            "<svg viewBox=\"0 0 10 5.5\" \
+             xmlns=\"http://www.w3.org/2000/svg\"><defs>\
+             <clipPath id=\"c\"><rect width=\"10\"/></clipPath>\
+             <linearGradient gradientUnits=\"userSpaceOnUse\">\
+             <stop offset=\"0\" stop-color=\"red\"/></linearGradient>\
+             </defs></svg>"
+        );
+    }
+
+    #[test]
+    fn self_closing_shapes() {
+        assert_eq!(
+            render(g((), (
+                circle(attr::r(1.)),
+                line(attr::x1(0.)),
+                polygon(()),
+                use_("#a", ()),
+                rect::content((), title("t")),
+            ))),
+            "<g><circle r=\"1\"/><line x1=\"0\"/><polygon/>\
+             <use href=\"#a\"/><rect><title>t</title></rect></g>"
+        );
+    }
+
+    #[test]
+    fn phrasing_root() {
+        assert_eq!(
+            render(strict::p(("a", svg((), circle(()))))),
+            "<p>a<svg><circle/></svg></p>"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 use crate::escape;
-use crate::core::{AttributeValue, Content, Target, Text};
+use crate::core::{
+    Attributes, AttributeValue, Content, DescriptionItems, Flow, ListItems,
+    Phrasing, TableCells, TableRows, Target, Text,
+};
 
 
 //------------ debug ---------------------------------------------------------
@@ -31,6 +34,9 @@ impl<C: fmt::Debug> AttributeValue for Debug<C> {
     }
 }
 
+impl<C: fmt::Debug> Flow for Debug<C> { }
+impl<C: fmt::Debug> Phrasing for Debug<C> { }
+
 
 //------------ display -------------------------------------------------------
 
@@ -60,6 +66,9 @@ impl<C: fmt::Display> AttributeValue for Display<C> {
     }
 }
 
+impl<C: fmt::Display> Flow for Display<C> { }
+impl<C: fmt::Display> Phrasing for Display<C> { }
+
 
 //------------ either --------------------------------------------------------
 
@@ -103,6 +112,18 @@ where
 {
 }
 
+macro_rules! either_category {
+    ( $( $category:ident ),* ) => {
+        $(
+            impl<T: $category, U: $category> $category for Either<T, U> { }
+        )*
+    }
+}
+
+either_category!(
+    Flow, Phrasing, ListItems, DescriptionItems, TableRows, TableCells
+);
+
 
 //------------ iter ----------------------------------------------------------
 
@@ -121,6 +142,28 @@ where I: Iterator, I::Item: Content {
     }
 }
 
+macro_rules! iter_category {
+    ( $( $category:ident ),* ) => {
+        $(
+            impl<I> $category for Iter<I>
+            where I: Iterator, I::Item: $category { }
+        )*
+    }
+}
+
+iter_category!(
+    Flow, Phrasing, ListItems, DescriptionItems, TableRows, TableCells
+);
+
+impl<I> Attributes for Iter<I>
+where I: Iterator, I::Item: Attributes {
+    fn render_attrs(self, target: &mut Target) {
+        for item in self.0 {
+            item.render_attrs(target);
+        }
+    }
+}
+
 
 //------------ join ----------------------------------------------------------
 
@@ -153,3 +196,8 @@ where
     }
 }
 
+impl<J, I> Flow for Join<J, I>
+where J: Flow + Clone, I: Iterator, I::Item: Flow { }
+
+impl<J, I> Phrasing for Join<J, I>
+where J: Phrasing + Clone, I: Iterator, I::Item: Phrasing { }
@@ -0,0 +1,108 @@
+//! Tests for the derive macros.
+//!
+//! These need the `derive` feature, e.g., `cargo test --features derive`.
+
+use htmlfn::core::Content;
+use htmlfn::form::Form;
+use htmlfn::html::strict;
+
+fn render(content: impl Content) -> String {
+    String::from_utf8(content.render().as_ref().into()).unwrap()
+}
+
+
+//------------ Content -------------------------------------------------------
+
+#[derive(Content)]
+#[html(tag = "article", class = "card")]
+struct Card {
+    #[html(tag = "h2", class = "title")]
+    title: &'static str,
+    #[html(tag = "p", display)]
+    views: u32,
+    #[html(skip)]
+    #[allow(dead_code)]
+    id: u64,
+}
+
+#[derive(Content)]
+struct Person {
+    #[html(label = "Name")]
+    name: &'static str,
+    #[html(label = "Age", display)]
+    age: u8,
+}
+
+#[derive(Content)]
+#[html(tag = "li")]
+struct Entry(&'static str);
+
+#[test]
+fn content_in_strict() {
+    assert_eq!(
+        render(strict::main((
+            Card { title: "a < b", views: 12, id: 7 },
+            Person { name: "Ann", age: 42 },
+        ))),
+        "<main><article class=\"card\"><h2 class=\"title\">a &lt; b</h2>\
+         <p>12</p></article><dl><dt>Name</dt><dd>Ann</dd>\
+         <dt>Age</dt><dd>42</dd></dl></main>"
+    );
+    assert_eq!(
+        render(strict::ul((Entry("x"), Entry("y")))),
+        "<ul><li>x</li><li>y</li></ul>"
+    );
+}
+
+
+//------------ Form ----------------------------------------------------------
+
+const COLORS: &[(&str, &str)] = &[("r", "Red"), ("g", "Green")];
+
+#[derive(Debug, Form, PartialEq)]
+struct Order {
+    #[form(options = COLORS, label = "Colour")]
+    color: String,
+    #[form(min = 1, max = 10)]
+    count: Option<u8>,
+    #[form(pattern = "[A-Z]{3}")]
+    code: String,
+    #[form(textarea)]
+    note: Option<String>,
+}
+
+#[test]
+fn form_decode() {
+    assert_eq!(
+        Order::decode(b"color=g&count=2&code=ABC&note=").unwrap(),
+        Order {
+            color: "g".into(), count: Some(2), code: "ABC".into(),
+            note: None,
+        }
+    );
+    assert_eq!(
+        Order::decode(b"color=x&count=11&code=A").unwrap_err().to_string(),
+        "invalid form data; color: Please select one of the options.; \
+         count: Please enter at most 10."
+    );
+}
+
+#[test]
+fn form_render() {
+    let res = render(Order::form("/order"));
+    assert!(res.contains(
+        "<label for=\"color\">Colour</label><select id=\"color\" \
+         name=\"color\" required><option value=\"r\">Red</option>"
+    ), "{}", res);
+    assert!(res.contains(
+        "<input type=\"number\" id=\"count\" name=\"count\" min=\"1\" \
+         max=\"10\"/>"
+    ), "{}", res);
+    assert!(res.contains(
+        "<input type=\"text\" id=\"code\" name=\"code\" required \
+         pattern=\"[A-Z]{3}\"/>"
+    ), "{}", res);
+    assert!(res.contains(
+        "<textarea id=\"note\" name=\"note\"></textarea>"
+    ), "{}", res);
+}